@@ -2,6 +2,7 @@
 //!
 //! This module exposes the Application, which is the core struct of tui-realm.
 
+use std::collections::HashMap;
 use std::hash::Hash;
 use std::time::{Duration, Instant};
 
@@ -9,9 +10,12 @@ use ratatui::Frame;
 use thiserror::Error;
 
 use super::{Subscription, View, WrappedComponent};
+use crate::event::KeyEvent;
 use crate::listener::{EventListener, EventListenerCfg, ListenerError};
 use crate::ratatui::layout::Rect;
-use crate::{AttrValue, Attribute, Event, Injector, State, Sub, SubEventClause, ViewError};
+use crate::{
+    AttrValue, Attribute, Event, FocusChange, Injector, State, Sub, SubEventClause, ViewError,
+};
 
 /// Result retuned by [`Application`].
 /// Ok depends on method
@@ -33,6 +37,12 @@ where
     /// If true, subs won't be processed. (Default: False)
     sub_lock: bool,
     view: View<ComponentId, Msg, UserEvent>,
+    /// If set, window resize events are coalesced until no resize is received for this amount of time
+    resize_debounce: Option<Duration>,
+    /// Last window resize received (width, height, time) and not forwarded yet
+    pending_resize: Option<(u16, u16, Instant)>,
+    /// If true, clicking on a component gives focus to it. (Default: False)
+    click_to_focus: bool,
 }
 
 impl<ComponentId, Msg, UserEvent> Application<ComponentId, Msg, UserEvent>
@@ -54,6 +64,9 @@ where
             subs: Vec::new(),
             sub_lock: false,
             view: View::default(),
+            resize_debounce: None,
+            pending_resize: None,
+            click_to_focus: false,
         }
     }
 
@@ -84,7 +97,10 @@ where
     /// The workflow of the tick method is the following one:
     ///
     /// 1. The event listener is fetched according to the provided [`PollStrategy`]
+    ///    (window resize events are coalesced if a resize debounce is set. See [`Application::set_resize_debounce`])
     /// 2. All the received events are sent to the current active component
+    ///    (if click to focus is enabled, when a mouse button is pressed on a rendered component, that component
+    ///    gets focus first. See [`Application::set_click_to_focus`])
     /// 3. All the received events are forwarded to the subscribed components which satisfy the received events and conditions.
     /// 4. Returns messages to process
     ///
@@ -94,6 +110,7 @@ where
     pub fn tick(&mut self, strategy: PollStrategy) -> ApplicationResult<Vec<Msg>> {
         // Poll event listener
         let events = self.poll(strategy)?;
+        let events = self.debounce_resize(events);
         // Forward to active element
         let mut messages: Vec<Msg> = events
             .iter()
@@ -106,6 +123,34 @@ where
         Ok(messages)
     }
 
+    /// Set the debounce for window resize events.
+    ///
+    /// When set, [`Event::WindowResize`] events are not forwarded as soon as they're received; instead only the last one
+    /// is forwarded, once no other resize has been received for the provided `debounce`.
+    /// This prevents excessive re-layouts while the user is dragging the terminal window.
+    /// Since the pending resize is only checked on [`Application::tick`], make sure to call it periodically (e.g. enabling `Tick`).
+    ///
+    /// If `None` (default), resize events are forwarded immediately.
+    /// When the debounce is disabled while a resize is pending, the pending resize is forwarded on the next `tick`.
+    pub fn set_resize_debounce(&mut self, debounce: Option<Duration>) {
+        self.resize_debounce = debounce;
+    }
+
+    /// Set whether clicking on a component gives focus to it.
+    ///
+    /// When enabled, on a mouse button press the component rendered at that position in the last frame becomes the
+    /// active component, before the event is forwarded to it. This requires mouse capture to be enabled.
+    /// Only the components rendered in the last frame can be clicked, so components which are mounted but not
+    /// rendered anymore never get focus this way.
+    ///
+    /// When a popup is rendered with [`Application::view_over`], the components rendered before it in the same frame
+    /// can't be clicked, so the popup keeps focus when the user clicks outside of it.
+    ///
+    /// Disabled by default.
+    pub fn set_click_to_focus(&mut self, enabled: bool) {
+        self.click_to_focus = enabled;
+    }
+
     // -- view bridge
 
     /// Add an injector to the view
@@ -129,6 +174,24 @@ where
         Ok(())
     }
 
+    /// Mount all the provided components to view, each one with its subscriptions.
+    /// Components are mounted in the order they're yielded.
+    /// Returns error as soon as a component is already mounted; components mounted before it are kept.
+    pub fn mount_all<I>(&mut self, components: I) -> ApplicationResult<()>
+    where
+        I: IntoIterator<
+            Item = (
+                ComponentId,
+                WrappedComponent<Msg, UserEvent>,
+                Vec<Sub<ComponentId, UserEvent>>,
+            ),
+        >,
+    {
+        components
+            .into_iter()
+            .try_for_each(|(id, component, subs)| self.mount(id, component, subs))
+    }
+
     /// Umount component associated to `id` and remove ALL its SUBSCRIPTIONS.
     /// Returns Error if the component doesn't exist
     pub fn umount(&mut self, id: &ComponentId) -> ApplicationResult<()> {
@@ -139,20 +202,21 @@ where
 
     /// Remount provided component.
     /// Returns Err if failed to mount. It ignores whether the component already exists or not.
-    /// If component had focus, focus is preserved
+    /// If component had focus, focus is preserved.
+    /// Returns the component previously mounted with `id`, if any
     pub fn remount(
         &mut self,
         id: ComponentId,
         component: WrappedComponent<Msg, UserEvent>,
         subs: Vec<Sub<ComponentId, UserEvent>>,
-    ) -> ApplicationResult<()> {
+    ) -> ApplicationResult<Option<WrappedComponent<Msg, UserEvent>>> {
         // remove subs
         self.unsubscribe_component(&id);
         // remount into view
-        self.view.remount(&id, component)?;
+        let previous = self.view.remount(&id, component)?;
         // re-add subs
         self.insert_subscriptions(&id, subs);
-        Ok(())
+        Ok(previous)
     }
 
     /// Umount all components in the view and removed all associated subscriptions
@@ -166,11 +230,41 @@ where
         self.view.mounted(id)
     }
 
-    /// Render component called `id`
+    /// Render component called `id`.
+    /// Hidden components (`Attribute::Display` set to `AttrValue::Flag(false)`) are not rendered.
     pub fn view(&mut self, id: &ComponentId, f: &mut Frame, area: Rect) {
         self.view.view(id, f, area);
     }
 
+    /// Clear `area` and render component `id` on top of it, which is what you want to render popups.
+    /// The area of a popup can be computed with [`crate::utils::centered_rect`].
+    ///
+    /// > NOTE: rendering doesn't change focus; call [`Application::active`] to let the popup handle the events.
+    /// > If click to focus is enabled, the components rendered before the popup can't be clicked while it is rendered
+    /// > (see [`Application::set_click_to_focus`]).
+    pub fn view_over(&mut self, id: &ComponentId, f: &mut Frame, area: Rect) {
+        self.view.view_over(id, f, area);
+    }
+
+    /// Render all the provided components, each one in its area.
+    /// Components are rendered in the provided order, so in case of overlapping areas, the last one is drawn on top.
+    /// Ids of components which are not mounted are skipped.
+    pub fn view_all(&mut self, f: &mut Frame, components: &[(ComponentId, Rect)]) {
+        self.view.view_all(f, components);
+    }
+
+    /// Returns the ids of the mounted components, in mounting order.
+    /// Remounted components keep their original position.
+    pub fn mounted_ids(&self) -> Vec<&ComponentId> {
+        self.view.mounted_ids()
+    }
+
+    /// Returns the ids of the mounted components which are not hidden, in mounting order.
+    /// Useful to compute layouts only over the visible components
+    pub fn visible_components(&self) -> Vec<&ComponentId> {
+        self.view.visible_components()
+    }
+
     /// Query view component for a certain `AttrValue`
     /// Returns error if the component doesn't exist
     /// Returns None if the attribute doesn't exist.
@@ -195,12 +289,42 @@ where
             .map_err(ApplicationError::from)
     }
 
+    /// Set all the provided attributes, in order.
+    /// Returns error if any of the components doesn't exist; in this case no attribute is set at all.
+    pub fn attr_all(
+        &mut self,
+        attrs: Vec<(ComponentId, Attribute, AttrValue)>,
+    ) -> ApplicationResult<()> {
+        self.view.attr_all(attrs).map_err(ApplicationError::from)
+    }
+
     /// Get state for component `id`.
     /// Returns `Err` if component doesn't exist
     pub fn state(&self, id: &ComponentId) -> ApplicationResult<State> {
         self.view.state(id).map_err(ApplicationError::from)
     }
 
+    /// Get the states of all the provided components at once (e.g. to submit a form).
+    /// Components which don't exist are skipped.
+    pub fn states(&self, ids: &[ComponentId]) -> HashMap<ComponentId, State> {
+        self.view.states(ids)
+    }
+
+    /// Get the name of component `id`, useful for debugging and logging (see [`crate::Component::name`]).
+    /// Returns `Err` if component doesn't exist
+    pub fn component_name(&self, id: &ComponentId) -> ApplicationResult<&'static str> {
+        self.view.component_name(id).map_err(ApplicationError::from)
+    }
+
+    /// Get the key bindings declared by component `id`, e.g. to build a help overlay (see [`crate::Component::key_bindings`]).
+    /// Returns `Err` if component doesn't exist
+    pub fn key_bindings(
+        &self,
+        id: &ComponentId,
+    ) -> ApplicationResult<Vec<(KeyEvent, &'static str)>> {
+        self.view.key_bindings(id).map_err(ApplicationError::from)
+    }
+
     /// Shorthand for `attr(id, Attribute::Focus(AttrValue::Flag(true)))`.
     /// It also sets the component as the current one having focus.
     /// Previous active component, if any, GETS PUSHED to the STACK
@@ -226,6 +350,31 @@ where
         self.view.focus()
     }
 
+    /// Returns whether component `id` is the current active component.
+    /// Returns `false` if the component is not mounted
+    pub fn has_focus(&self, id: &ComponentId) -> bool {
+        self.view.has_focus(id)
+    }
+
+    /// Set whether focus changes should be recorded, to be taken with [`Application::take_focus_changes`].
+    /// Disabling the tracking discards the changes not taken yet.
+    ///
+    /// Disabled by default.
+    pub fn set_track_focus_changes(&mut self, enabled: bool) {
+        self.view.set_track_focus_changes(enabled);
+    }
+
+    /// Take all the focus changes occurred since the last call.
+    /// A change is only reported when the focused component actually changes (e.g. calling `active` on the
+    /// component which already has focus won't report anything).
+    ///
+    /// Changes are recorded only once enabled with [`Application::set_track_focus_changes`], and then they're
+    /// kept until taken, so you should call this method in your main loop (e.g. after `tick`)
+    /// to react to them in your `Update` routine.
+    pub fn take_focus_changes(&mut self) -> Vec<FocusChange<ComponentId>> {
+        self.view.take_focus_changes()
+    }
+
     // -- subs bridge
 
     /// Subscribe component to a certain event.
@@ -299,6 +448,36 @@ where
         }
     }
 
+    /// Coalesce window resize events in `events` according to the resize debounce.
+    /// The last resize is kept pending and it's pushed to the events once the debounce is elapsed.
+    fn debounce_resize(&mut self, events: Vec<Event<UserEvent>>) -> Vec<Event<UserEvent>> {
+        let Some(debounce) = self.resize_debounce else {
+            // Flush the resize left pending when the debounce has been disabled
+            return match self.pending_resize.take() {
+                Some((width, height, _)) => std::iter::once(Event::WindowResize(width, height))
+                    .chain(events)
+                    .collect(),
+                None => events,
+            };
+        };
+        let mut forward = Vec::with_capacity(events.len());
+        for ev in events {
+            match ev {
+                Event::WindowResize(width, height) => {
+                    self.pending_resize = Some((width, height, Instant::now()));
+                }
+                ev => forward.push(ev),
+            }
+        }
+        if let Some((width, height, received)) = self.pending_resize {
+            if received.elapsed() >= debounce {
+                self.pending_resize = None;
+                forward.push(Event::WindowResize(width, height));
+            }
+        }
+        forward
+    }
+
     /// Poll listener according to provided strategy
     fn poll(&mut self, strategy: PollStrategy) -> ApplicationResult<Vec<Event<UserEvent>>> {
         match strategy {
@@ -409,6 +588,10 @@ where
 
     /// Forward event to current active component, if any.
     fn forward_to_active_component(&mut self, ev: Event<UserEvent>) -> Option<Msg> {
+        // Clicking on a component gives focus to it
+        if self.click_to_focus {
+            self.view.focus_clicked(&ev);
+        }
         self.view
             .focus()
             .cloned()
@@ -504,10 +687,12 @@ mod test {
     use pretty_assertions::assert_eq;
 
     use super::*;
-    use crate::event::{Key, KeyEvent};
+    use crate::event::{Key, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
     use crate::mock::{
         MockBarInput, MockComponentId, MockEvent, MockFooInput, MockInjector, MockMsg, MockPoll,
     };
+    use crate::ratatui::Terminal;
+    use crate::ratatui::backend::TestBackend;
     use crate::{StateValue, SubClause};
 
     #[test]
@@ -608,9 +793,14 @@ mod test {
             State::One(StateValue::String(String::default()))
         );
         // Active / blur
+        application.set_track_focus_changes(true);
         assert!(application.active(&MockComponentId::InputFoo).is_ok());
         assert!(application.active(&MockComponentId::InputBar).is_ok());
         assert!(application.active(&MockComponentId::InputOmar).is_err());
+        assert_eq!(
+            application.take_focus_changes().last(),
+            Some(&FocusChange::Focus(MockComponentId::InputBar))
+        );
         assert!(application.blur().is_ok());
         assert!(application.blur().is_ok());
         // no focus
@@ -1308,6 +1498,67 @@ mod test {
         );
     }
 
+    #[test]
+    fn should_debounce_window_resize() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init(listener_config());
+        // No debounce
+        assert_eq!(
+            application.debounce_resize(vec![
+                Event::WindowResize(10, 10),
+                Event::WindowResize(20, 20)
+            ]),
+            vec![Event::WindowResize(10, 10), Event::WindowResize(20, 20)]
+        );
+        // Burst of resize events; a long debounce holds the resize regardless of the runner speed
+        application.set_resize_debounce(Some(Duration::from_secs(60)));
+        assert_eq!(
+            application.debounce_resize(vec![
+                Event::WindowResize(10, 10),
+                Event::WindowResize(20, 20),
+                Event::Tick,
+                Event::WindowResize(30, 30),
+            ]),
+            vec![Event::Tick]
+        );
+        assert_eq!(
+            application.debounce_resize(vec![Event::Tick]),
+            vec![Event::Tick]
+        );
+        // Once the debounce is elapsed, only the last resize is forwarded
+        application.set_resize_debounce(Some(Duration::from_millis(50)));
+        std::thread::sleep(Duration::from_millis(60));
+        assert_eq!(
+            application.debounce_resize(vec![Event::Tick]),
+            vec![Event::Tick, Event::WindowResize(30, 30)]
+        );
+        assert_eq!(
+            application.debounce_resize(vec![Event::Tick]),
+            vec![Event::Tick]
+        );
+    }
+
+    #[test]
+    fn should_flush_pending_resize_when_debounce_is_disabled() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init(listener_config());
+        application.set_resize_debounce(Some(Duration::from_secs(60)));
+        assert_eq!(
+            application.debounce_resize(vec![Event::WindowResize(10, 10), Event::Tick]),
+            vec![Event::Tick]
+        );
+        // Disable debounce while the resize is pending
+        application.set_resize_debounce(None);
+        assert_eq!(
+            application.debounce_resize(vec![Event::WindowResize(20, 20)]),
+            vec![Event::WindowResize(10, 10), Event::WindowResize(20, 20)]
+        );
+        assert_eq!(
+            application.debounce_resize(vec![Event::Tick]),
+            vec![Event::Tick]
+        );
+    }
+
     #[test]
     fn application_should_add_injectors() {
         let mut application: Application<MockComponentId, MockMsg, MockEvent> =
@@ -1315,6 +1566,163 @@ mod test {
         application.add_injector(Box::new(MockInjector));
     }
 
+    #[test]
+    fn should_tell_whether_component_has_focus() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init(listener_config());
+        assert_eq!(application.mounted(&MockComponentId::InputFoo), false);
+        assert_eq!(application.has_focus(&MockComponentId::InputFoo), false);
+        assert!(
+            application
+                .mount(
+                    MockComponentId::InputFoo,
+                    Box::new(MockFooInput::default()),
+                    vec![]
+                )
+                .is_ok()
+        );
+        assert!(
+            application
+                .mount(
+                    MockComponentId::InputBar,
+                    Box::new(MockBarInput::default()),
+                    vec![]
+                )
+                .is_ok()
+        );
+        assert!(application.mounted(&MockComponentId::InputFoo));
+        assert_eq!(application.has_focus(&MockComponentId::InputFoo), false);
+        assert!(application.active(&MockComponentId::InputFoo).is_ok());
+        assert!(application.has_focus(&MockComponentId::InputFoo));
+        assert_eq!(application.has_focus(&MockComponentId::InputBar), false);
+        assert!(application.active(&MockComponentId::InputBar).is_ok());
+        assert_eq!(application.has_focus(&MockComponentId::InputFoo), false);
+        assert!(application.has_focus(&MockComponentId::InputBar));
+        // Umounted components never have focus
+        assert!(application.umount(&MockComponentId::InputBar).is_ok());
+        assert_eq!(application.mounted(&MockComponentId::InputBar), false);
+        assert_eq!(application.has_focus(&MockComponentId::InputBar), false);
+        assert!(application.has_focus(&MockComponentId::InputFoo));
+    }
+
+    #[test]
+    fn should_mount_all_components() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init(listener_config());
+        let ids: Vec<MockComponentId> = (0..5)
+            .map(|x| MockComponentId::Dyn(format!("panel-{x}")))
+            .collect();
+        assert!(
+            application
+                .mount_all(ids.iter().map(|id| {
+                    (
+                        id.clone(),
+                        Box::new(MockFooInput::default()) as WrappedComponent<MockMsg, MockEvent>,
+                        vec![Sub::new(SubEventClause::Tick, SubClause::Always)],
+                    )
+                }))
+                .is_ok()
+        );
+        assert!(ids.iter().all(|id| application.mounted(id)));
+        assert_eq!(application.mounted_ids(), ids.iter().collect::<Vec<_>>());
+        assert_eq!(application.subs.len(), 5);
+        // Mounting an already mounted component fails
+        assert!(
+            application
+                .mount_all(vec![
+                    (
+                        MockComponentId::InputBar,
+                        Box::new(MockBarInput::default()) as WrappedComponent<MockMsg, MockEvent>,
+                        vec![],
+                    ),
+                    (
+                        ids[0].clone(),
+                        Box::new(MockFooInput::default()) as WrappedComponent<MockMsg, MockEvent>,
+                        vec![],
+                    ),
+                ])
+                .is_err()
+        );
+        assert!(application.mounted(&MockComponentId::InputBar));
+    }
+
+    #[test]
+    fn should_give_focus_to_clicked_component() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init(listener_config());
+        assert!(
+            application
+                .mount(
+                    MockComponentId::InputFoo,
+                    Box::new(MockFooInput::default()),
+                    vec![]
+                )
+                .is_ok()
+        );
+        assert!(
+            application
+                .mount(
+                    MockComponentId::InputBar,
+                    Box::new(MockBarInput::default()),
+                    vec![]
+                )
+                .is_ok()
+        );
+        assert!(application.active(&MockComponentId::InputFoo).is_ok());
+        let mut terminal = Terminal::new(TestBackend::new(20, 10)).unwrap();
+        terminal
+            .draw(|f| {
+                application.view(&MockComponentId::InputFoo, f, Rect::new(0, 0, 20, 5));
+                application.view(&MockComponentId::InputBar, f, Rect::new(0, 5, 20, 5));
+            })
+            .unwrap();
+        let click = |row| {
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                modifiers: KeyModifiers::NONE,
+                column: 4,
+                row,
+            })
+        };
+        // Disabled by default
+        application.forward_to_active_component(click(7));
+        assert_eq!(application.focus(), Some(&MockComponentId::InputFoo));
+        application.set_click_to_focus(true);
+        application.forward_to_active_component(click(7));
+        assert_eq!(application.focus(), Some(&MockComponentId::InputBar));
+        application.forward_to_active_component(click(2));
+        assert_eq!(application.focus(), Some(&MockComponentId::InputFoo));
+    }
+
+    #[test]
+    fn should_forward_terminal_focus_events_to_subscribers() {
+        let mut application: Application<MockComponentId, MockMsg, MockEvent> =
+            Application::init(listener_config());
+        assert!(
+            application
+                .mount(
+                    MockComponentId::InputFoo,
+                    Box::new(MockFooInput::default()),
+                    vec![]
+                )
+                .is_ok()
+        );
+        assert!(
+            application
+                .mount(
+                    MockComponentId::InputBar,
+                    Box::new(MockBarInput::default()),
+                    vec![Sub::new(SubEventClause::FocusLost, SubClause::Always)]
+                )
+                .is_ok()
+        );
+        assert!(application.active(&MockComponentId::InputFoo).is_ok());
+        let mut messages = Vec::new();
+        application
+            .forward_to_subscriptions(&[Event::FocusGained, Event::FocusLost], &mut messages);
+        assert_eq!(messages, vec![MockMsg::BarTerminalFocus(false)]);
+    }
+
     fn listener_config() -> EventListenerCfg<MockEvent> {
         EventListenerCfg::default().add_port(
             Box::new(MockPoll::<MockEvent>::default()),
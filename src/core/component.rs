@@ -5,6 +5,7 @@
 use ratatui::Frame;
 
 use crate::command::{Cmd, CmdResult};
+use crate::event::KeyEvent;
 use crate::ratatui::layout::Rect;
 use crate::{AttrValue, Attribute, Event, State};
 
@@ -61,4 +62,40 @@ where
     /// Returns a Msg to the view.
     /// If [`None`] is returned it means there's no message to return for the provided event.
     fn on(&mut self, ev: Event<UserEvent>) -> Option<Msg>;
+
+    /// Returns whether the component is interested in the provided event.
+    /// If `false` is returned, the `View` won't forward the event to the component and `on` won't be called at all.
+    ///
+    /// This is useful for read-only components (e.g. a label), which shouldn't claim any key.
+    /// By default all the events are accepted.
+    fn can_handle(&self, _ev: &Event<UserEvent>) -> bool {
+        true
+    }
+
+    /// Returns a name for the component, to be used for debugging and logging.
+    ///
+    /// By default it returns the type name of the component (see [`std::any::type_name`]),
+    /// whose exact content is not guaranteed to be stable.
+    fn name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
+    /// Returns the keys handled by the component, each one with a short description of what it does.
+    /// This can be used to build a help overlay for the component having focus.
+    ///
+    /// By default it returns no key binding.
+    fn key_bindings(&self) -> Vec<(KeyEvent, &'static str)> {
+        Vec::new()
+    }
+
+    /// Called by the `View` once the component has been mounted and its properties have been injected.
+    /// This is the place to initialize whatever depends on the injected properties.
+    ///
+    /// By default it does nothing.
+    fn on_mount(&mut self) {}
+
+    /// Called by the `View` right before the component is umounted or replaced by another component.
+    ///
+    /// By default it does nothing.
+    fn on_umount(&mut self) {}
 }
@@ -22,9 +22,13 @@ where
     Mouse(MouseEvent),
     /// This event is raised after the terminal window is resized
     WindowResize(u16, u16),
-    /// Window focus gained
+    /// Window focus gained.
+    ///
+    /// With crossterm, it is raised only if focus reporting has been enabled (see `crossterm::event::EnableFocusChange`)
     FocusGained,
-    /// Window focus lost
+    /// Window focus lost.
+    ///
+    /// With crossterm, it is raised only if focus reporting has been enabled (see `crossterm::event::EnableFocusChange`)
     FocusLost,
     /// Clipboard content pasted
     Paste(String),
@@ -65,6 +69,14 @@ where
         matches!(self, Self::Tick)
     }
 
+    pub(crate) fn as_focus_gained(&self) -> bool {
+        matches!(self, Self::FocusGained)
+    }
+
+    pub(crate) fn as_focus_lost(&self) -> bool {
+        matches!(self, Self::FocusLost)
+    }
+
     pub(crate) fn as_user(&self) -> Option<&UserEvent> {
         if let Event::User(u) = self {
             Some(u)
@@ -14,11 +14,11 @@ mod view;
 
 // -- export
 pub use component::{Component, MockComponent};
-pub use state::{State, StateValue};
+pub use state::{State, StateConversionError, StateValue};
 // -- internal
 pub(crate) use subscription::Subscription;
 pub(crate) use view::WrappedComponent;
-pub use view::{View, ViewError};
+pub use view::{FocusChange, View, ViewError};
 
 // -- Update
 
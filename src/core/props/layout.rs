@@ -68,11 +68,22 @@ impl Layout {
             .split(area)
             .to_vec()
     }
+
+    /// Split an `Area` into chunks using the current layout configuration and assign each chunk to the id
+    /// at the same position in `ids`. The returned pairs can be rendered with `Application::view_all`.
+    ///
+    /// If `ids` and constraints have a different length, the exceeding ids or chunks are discarded.
+    /// Layouts can be nested by splitting again the area of an id.
+    pub fn chunks_for<T: Clone>(&self, ids: &[T], area: Rect) -> Vec<(T, Rect)> {
+        ids.iter().cloned().zip(self.chunks(area)).collect()
+    }
 }
 
 #[cfg(test)]
 mod test {
 
+    use pretty_assertions::assert_eq;
+
     use super::*;
 
     #[test]
@@ -90,4 +101,49 @@ mod test {
             ]);
         assert_eq!(layout.chunks(area).len(), 3);
     }
+
+    #[test]
+    fn should_assign_chunks_to_ids() {
+        let area = Rect::new(0, 0, 40, 20);
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(&[
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(1),
+            ])
+            .chunks_for(&["header", "body", "footer"], area);
+        assert_eq!(
+            rows,
+            vec![
+                ("header", Rect::new(0, 0, 40, 3)),
+                ("body", Rect::new(0, 3, 40, 16)),
+                ("footer", Rect::new(0, 19, 40, 1)),
+            ]
+        );
+        assert_eq!(rows.iter().map(|(_, r)| r.height).sum::<u16>(), area.height);
+        // Nested layout
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(&[Constraint::Percentage(25), Constraint::Percentage(75)])
+            .chunks_for(&["menu", "content"], rows[1].1);
+        assert_eq!(
+            columns,
+            vec![
+                ("menu", Rect::new(0, 3, 10, 16)),
+                ("content", Rect::new(10, 3, 30, 16)),
+            ]
+        );
+        assert_eq!(
+            columns.iter().map(|(_, r)| r.width).sum::<u16>(),
+            area.width
+        );
+        // Exceeding ids are discarded
+        assert_eq!(
+            Layout::default()
+                .constraints(&[Constraint::Min(0)])
+                .chunks_for(&["a", "b"], area),
+            vec![("a", area)]
+        );
+    }
 }
@@ -58,6 +58,62 @@ impl Props {
     pub fn set(&mut self, query: Attribute, value: AttrValue) {
         self.attrs.insert(query, value);
     }
+
+    /// Merge `other` into these properties.
+    /// All the attributes set in `other` are copied, replacing the ones already set, while the attributes
+    /// which are not set in `other` are kept.
+    ///
+    /// > NOTE: an attribute explicitly set in `other` always wins, even if its value is the default one
+    /// > (e.g. `Color::Reset` as foreground), since it can't be told apart from an intentional value.
+    pub fn merge(&mut self, other: Props) {
+        self.attrs.extend(other.attrs);
+    }
+
+    // -- accessors
+
+    /// Returns whether the component should be displayed.
+    /// The component is visible unless `Attribute::Display` is set to `AttrValue::Flag(false)`
+    pub fn is_visible(&self) -> bool {
+        self.get_ref(Attribute::Display)
+            .and_then(AttrValue::as_flag)
+            .unwrap_or(true)
+    }
+
+    /// Get, if set as `AttrValue::Color`, the `Attribute::Foreground` color
+    pub fn foreground(&self) -> Option<Color> {
+        self.get_ref(Attribute::Foreground)
+            .and_then(AttrValue::as_color)
+    }
+
+    /// Get, if set as `AttrValue::Color`, the `Attribute::Background` color
+    pub fn background(&self) -> Option<Color> {
+        self.get_ref(Attribute::Background)
+            .and_then(AttrValue::as_color)
+    }
+
+    /// Get the `Attribute::TextProps` modifiers, or empty modifiers if not set
+    pub fn modifiers(&self) -> TextModifiers {
+        self.get_ref(Attribute::TextProps)
+            .and_then(AttrValue::as_text_modifiers)
+            .unwrap_or_else(TextModifiers::empty)
+    }
+
+    /// Compose the style of the component.
+    /// It starts from `Attribute::Style` (or the default style), then applies the foreground and background colors
+    /// and adds the text modifiers.
+    pub fn style(&self) -> Style {
+        let mut style = self
+            .get_ref(Attribute::Style)
+            .and_then(AttrValue::as_style)
+            .unwrap_or_default();
+        if let Some(fg) = self.foreground() {
+            style = style.fg(fg);
+        }
+        if let Some(bg) = self.background() {
+            style = style.bg(bg);
+        }
+        style.add_modifier(self.modifiers())
+    }
 }
 
 /// Describes a "selector" to query an attribute on props.
@@ -85,6 +141,7 @@ pub enum Attribute {
     /// Whether to display or not the component. This should be reserved to hide components.
     /// As shown in stdlib and in example, its value should be `AttrValue::Flag` and should be checked on top of the
     /// `view()` method to choose whether to or not to render the component.
+    /// The `Application` won't render at all the components whose value is `AttrValue::Flag(false)`.
     Display,
     /// Reserved for tracking focus on component.
     /// You should not implement focus by yourself, since it's already read/written by the `active()` and `blur()` methods on
@@ -501,6 +558,97 @@ mod test {
         );
     }
 
+    #[test]
+    fn should_get_props_accessors() {
+        let mut props = Props::default();
+        assert_eq!(props.is_visible(), true);
+        assert_eq!(props.foreground(), None);
+        assert_eq!(props.background(), None);
+        assert_eq!(props.modifiers(), TextModifiers::empty());
+        assert_eq!(props.style(), Style::default());
+        props.set(Attribute::Display, AttrValue::Flag(false));
+        assert_eq!(props.is_visible(), false);
+        props.set(Attribute::Display, AttrValue::Flag(true));
+        assert_eq!(props.is_visible(), true);
+    }
+
+    #[test]
+    fn should_compose_style() {
+        let mut props = Props::default();
+        props.set(Attribute::Foreground, AttrValue::Color(Color::Red));
+        props.set(Attribute::Background, AttrValue::Color(Color::Black));
+        props.set(
+            Attribute::TextProps,
+            AttrValue::TextModifiers(TextModifiers::BOLD | TextModifiers::ITALIC),
+        );
+        assert_eq!(props.foreground(), Some(Color::Red));
+        assert_eq!(props.background(), Some(Color::Black));
+        assert_eq!(
+            props.modifiers(),
+            TextModifiers::BOLD | TextModifiers::ITALIC
+        );
+        assert_eq!(
+            props.style(),
+            Style::default()
+                .fg(Color::Red)
+                .bg(Color::Black)
+                .add_modifier(TextModifiers::BOLD | TextModifiers::ITALIC)
+        );
+        // Colors and modifiers are applied on top of the style attribute
+        props.set(
+            Attribute::Style,
+            AttrValue::Style(
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(TextModifiers::UNDERLINED),
+            ),
+        );
+        assert_eq!(
+            props.style(),
+            Style::default()
+                .fg(Color::Red)
+                .bg(Color::Black)
+                .add_modifier(
+                    TextModifiers::BOLD | TextModifiers::ITALIC | TextModifiers::UNDERLINED
+                )
+        );
+    }
+
+    #[test]
+    fn should_merge_props() {
+        let mut props = Props::default();
+        props.set(Attribute::Foreground, AttrValue::Color(Color::Red));
+        props.set(Attribute::Background, AttrValue::Color(Color::Black));
+        props.set(Attribute::Text, AttrValue::String(String::from("base")));
+        let mut other = Props::default();
+        other.set(Attribute::Foreground, AttrValue::Color(Color::Yellow));
+        other.set(Attribute::Background, AttrValue::Color(Color::Reset));
+        other.set(Attribute::Custom("unit"), AttrValue::Flag(true));
+        props.merge(other);
+        // other wins on conflicts, even with default values
+        assert_eq!(
+            props.get(Attribute::Foreground),
+            Some(AttrValue::Color(Color::Yellow))
+        );
+        assert_eq!(
+            props.get(Attribute::Background),
+            Some(AttrValue::Color(Color::Reset))
+        );
+        // attributes not set in other are kept
+        assert_eq!(
+            props.get(Attribute::Text),
+            Some(AttrValue::String(String::from("base")))
+        );
+        assert_eq!(
+            props.get(Attribute::Custom("unit")),
+            Some(AttrValue::Flag(true))
+        );
+        // merging empty props changes nothing
+        let expected = props.clone();
+        props.merge(Props::default());
+        assert_eq!(props, expected);
+    }
+
     #[test]
     fn unwrapping_should_unwrap() {
         assert_eq!(
@@ -124,6 +124,27 @@ impl TableBuilder {
         self
     }
 
+    /// Add all the provided columns to the last row and then add a new row to the table.
+    /// This is equivalent to calling `add_col` for each span followed by `add_row`.
+    pub fn add_row_from(&mut self, spans: Vec<TextSpan>) -> &mut Self {
+        for span in spans {
+            self.add_col(span);
+        }
+        self.add_row()
+    }
+
+    /// Pad every row shorter than the widest one with `pad_with`, so that all the rows have the same
+    /// amount of columns. Rows which are already full width are left untouched.
+    pub fn normalize(&mut self, pad_with: TextSpan) -> &mut Self {
+        if let Some(table) = self.table.as_mut() {
+            let width = table.iter().map(Vec::len).max().unwrap_or(0);
+            for row in table.iter_mut() {
+                row.resize(width, pad_with.clone());
+            }
+        }
+        self
+    }
+
     /// Take table out of builder
     /// Don't call this method twice for any reasons!
     pub fn build(&mut self) -> Table {
@@ -174,6 +195,67 @@ mod test {
         assert_eq!(table.get(4).unwrap().len(), 1); // 1 cols
     }
 
+    #[test]
+    fn tables_from_rows() {
+        let table: Table = TableBuilder::default()
+            .add_col(TextSpan::from("name"))
+            .add_col(TextSpan::from("age"))
+            .add_row()
+            .add_col(TextSpan::from("christian"))
+            .add_col(TextSpan::from("23"))
+            .add_row()
+            .add_col(TextSpan::from("omar"))
+            .build();
+        let from_rows: Table = TableBuilder::default()
+            .add_row_from(vec![TextSpan::from("name"), TextSpan::from("age")])
+            .add_row_from(vec![TextSpan::from("christian"), TextSpan::from("23")])
+            .add_col(TextSpan::from("omar"))
+            .build();
+        assert_eq!(table, from_rows);
+        // Mixing with the incremental API completes the row in progress
+        let table: Table = TableBuilder::default()
+            .add_col(TextSpan::from("name"))
+            .add_row_from(vec![TextSpan::from("age")])
+            .build();
+        assert_eq!(
+            table,
+            vec![vec![TextSpan::from("name"), TextSpan::from("age")], vec![]]
+        );
+    }
+
+    #[test]
+    fn normalized_tables() {
+        let table: Table = TableBuilder::default()
+            .add_row_from(vec![
+                TextSpan::from("name"),
+                TextSpan::from("age"),
+                TextSpan::from("city"),
+            ])
+            .add_col(TextSpan::from("omar"))
+            .normalize(TextSpan::from("-"))
+            .build();
+        assert_eq!(
+            table,
+            vec![
+                vec![
+                    TextSpan::from("name"),
+                    TextSpan::from("age"),
+                    TextSpan::from("city"),
+                ],
+                vec![
+                    TextSpan::from("omar"),
+                    TextSpan::from("-"),
+                    TextSpan::from("-"),
+                ],
+            ]
+        );
+        // Empty table stays empty
+        let table: Table = TableBuilder::default()
+            .normalize(TextSpan::default())
+            .build();
+        assert_eq!(table, vec![vec![]]);
+    }
+
     #[test]
     fn text_span() {
         // default
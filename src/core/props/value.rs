@@ -3,6 +3,7 @@
 //! This module exposes the prop values
 
 use std::collections::{HashMap, LinkedList};
+use std::time::Duration;
 
 use super::{Alignment, Color, Dataset, InputType, Shape, Style, Table, TextSpan};
 
@@ -40,6 +41,7 @@ pub enum PropValue {
     F64(f64),
     F32(f32),
     Str(String),
+    Duration(Duration),
     // -- tui props
     Alignment(Alignment),
     Color(Color),
@@ -310,6 +312,15 @@ impl PropValue {
         }
     }
 
+    /// Unwrap PropValue as Duration.
+    /// Panics otherwise
+    pub fn unwrap_duration(self) -> Duration {
+        match self {
+            PropValue::Duration(d) => d,
+            _ => panic!("Called `unwrap_duration` on a bad value"),
+        }
+    }
+
     /// Unwrap PropValue as Alignment.
     /// Panics otherwise
     pub fn unwrap_alignment(self) -> Alignment {
@@ -319,6 +330,15 @@ impl PropValue {
         }
     }
 
+    /// Unwrap PropValue as Color.
+    /// Panics otherwise
+    pub fn unwrap_color(self) -> Color {
+        match self {
+            PropValue::Color(c) => c,
+            _ => panic!("Called `unwrap_color` on a bad value"),
+        }
+    }
+
     /// Unwrap PropValue as Dataset.
     /// Panics otherwise
     pub fn unwrap_dataset(self) -> Dataset {
@@ -507,6 +527,15 @@ impl PropValue {
         }
     }
 
+    /// Get a Duration value from PropValue, or None
+    pub fn as_duration(&self) -> Option<Duration> {
+        match self {
+            // cheap copy, so no reference
+            PropValue::Duration(v) => Some(*v),
+            _ => None,
+        }
+    }
+
     /// Get a Alignment value from PropValue, or None
     pub fn as_alignment(&self) -> Option<Alignment> {
         match self {
@@ -516,6 +545,15 @@ impl PropValue {
         }
     }
 
+    /// Get a Color value from PropValue, or None
+    pub fn as_color(&self) -> Option<Color> {
+        match self {
+            // cheap copy, so no reference
+            PropValue::Color(v) => Some(*v),
+            _ => None,
+        }
+    }
+
     /// Get a Dataset value from PropValue, or None
     pub fn as_dataset(&self) -> Option<&Dataset> {
         match self {
@@ -668,6 +706,11 @@ mod tests {
             Alignment::Center
         );
         assert!(PropValue::Bool(true).unwrap_bool());
+        assert_eq!(
+            PropValue::Duration(Duration::from_secs(90)).unwrap_duration(),
+            Duration::from_secs(90)
+        );
+        assert_eq!(PropValue::Color(Color::Red).unwrap_color(), Color::Red);
         assert_eq!(
             PropValue::Dataset(Dataset::default()).unwrap_dataset(),
             Dataset::default()
@@ -758,12 +801,24 @@ mod tests {
         );
         assert_eq!(PropValue::Bool(true).as_str(), None);
 
+        assert_eq!(
+            PropValue::Duration(Duration::from_millis(1500)).as_duration(),
+            Some(Duration::from_millis(1500))
+        );
+        assert_eq!(PropValue::Bool(true).as_duration(), None);
+
         assert_eq!(
             PropValue::Alignment(Alignment::Center).as_alignment(),
             Some(Alignment::Center)
         );
         assert_eq!(PropValue::Bool(true).as_alignment(), None);
 
+        assert_eq!(
+            PropValue::Color(Color::Rgb(255, 64, 32)).as_color(),
+            Some(Color::Rgb(255, 64, 32))
+        );
+        assert_eq!(PropValue::Bool(true).as_color(), None);
+
         assert_eq!(
             PropValue::Dataset(Dataset::default()).as_dataset(),
             Some(&Dataset::default())
@@ -2,7 +2,11 @@
 //!
 //! This module exposes the state type and values
 
+use std::cmp::Ordering;
 use std::collections::{HashMap, LinkedList};
+use std::fmt;
+
+use thiserror::Error;
 
 use crate::props::Color;
 use crate::utils::{Email, PhoneNumber};
@@ -20,7 +24,15 @@ pub enum State {
     None,
 }
 
-/// StateValue describes the value contained in a State
+/// StateValue describes the value contained in a State.
+///
+/// State values can be compared with each other:
+///
+/// - numbers are compared numerically, even if they have different types (e.g. `U8(4) > I32(-2)`).
+///   If they have the same value, but a different type, the variant declaration order is used.
+/// - strings are compared lexically
+/// - colors can only be compared for equality
+/// - values of different kinds are ordered by kind: `None < Bool < numbers < String < Color < Email < PhoneNumber`
 #[derive(Debug, PartialEq, Clone)]
 pub enum StateValue {
     None,
@@ -46,6 +58,20 @@ pub enum StateValue {
 }
 
 impl State {
+    /// Returns the rank of the variant; used to compare states of different kinds
+    fn rank(&self) -> u8 {
+        match self {
+            Self::None => 0,
+            Self::One(_) => 1,
+            Self::Tup2(_) => 2,
+            Self::Tup3(_) => 3,
+            Self::Tup4(_) => 4,
+            Self::Vec(_) => 5,
+            Self::Map(_) => 6,
+            Self::Linked(_) => 7,
+        }
+    }
+
     pub fn unwrap_one(self) -> StateValue {
         match self {
             Self::One(val) => val,
@@ -101,6 +127,123 @@ impl State {
     }
 }
 
+impl PartialOrd for State {
+    /// States are compared by their values (see [`StateValue`]); tuples, vectors and linked lists are compared lexicographically.
+    /// Maps can only be compared for equality, while states of different kinds are ordered by kind:
+    /// `None < One < Tup2 < Tup3 < Tup4 < Vec < Map < Linked`.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Self::None, Self::None) => Some(Ordering::Equal),
+            (Self::One(a), Self::One(b)) => a.partial_cmp(b),
+            (Self::Tup2(a), Self::Tup2(b)) => a.partial_cmp(b),
+            (Self::Tup3(a), Self::Tup3(b)) => a.partial_cmp(b),
+            (Self::Tup4(a), Self::Tup4(b)) => a.partial_cmp(b),
+            (Self::Vec(a), Self::Vec(b)) => a.partial_cmp(b),
+            (Self::Map(a), Self::Map(b)) => (a == b).then_some(Ordering::Equal),
+            (Self::Linked(a), Self::Linked(b)) => a.partial_cmp(b),
+            (a, b) => a.rank().partial_cmp(&b.rank()),
+        }
+    }
+}
+
+impl PartialOrd for StateValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if let (Some(a), Some(b)) = (self.as_number(), other.as_number()) {
+            return a
+                .compare(b)
+                .map(|ord| ord.then(self.rank().cmp(&other.rank())));
+        }
+        match (self, other) {
+            (Self::None, Self::None) => Some(Ordering::Equal),
+            (Self::Bool(a), Self::Bool(b)) => a.partial_cmp(b),
+            (Self::String(a), Self::String(b)) => a.partial_cmp(b),
+            (Self::Color(a), Self::Color(b)) => (a == b).then_some(Ordering::Equal),
+            (Self::Email(a), Self::Email(b)) => a.partial_cmp(b),
+            (Self::PhoneNumber(a), Self::PhoneNumber(b)) => a.partial_cmp(b),
+            (a, b) => a.rank().partial_cmp(&b.rank()),
+        }
+    }
+}
+
+/// Numeric representation of a [`StateValue`], used to compare numbers of different types
+#[derive(Debug, Clone, Copy)]
+enum Number {
+    Signed(i128),
+    Unsigned(u128),
+    Float(f64),
+}
+
+impl Number {
+    fn as_f64(self) -> f64 {
+        match self {
+            Self::Signed(n) => n as f64,
+            Self::Unsigned(n) => n as f64,
+            Self::Float(n) => n,
+        }
+    }
+
+    fn compare(self, other: Self) -> Option<Ordering> {
+        match (self, other) {
+            (Self::Signed(a), Self::Signed(b)) => Some(a.cmp(&b)),
+            (Self::Unsigned(a), Self::Unsigned(b)) => Some(a.cmp(&b)),
+            (Self::Signed(a), Self::Unsigned(b)) => match u128::try_from(a) {
+                Ok(a) => Some(a.cmp(&b)),
+                Err(_) => Some(Ordering::Less),
+            },
+            (Self::Unsigned(_), Self::Signed(_)) => other.compare(self).map(Ordering::reverse),
+            (Self::Float(a), b) => a.partial_cmp(&b.as_f64()),
+            (a, Self::Float(b)) => a.as_f64().partial_cmp(&b),
+        }
+    }
+}
+
+impl StateValue {
+    /// Returns the rank of the variant; used to compare values of different kinds
+    fn rank(&self) -> u8 {
+        match self {
+            Self::None => 0,
+            Self::Bool(_) => 1,
+            Self::U8(_) => 2,
+            Self::U16(_) => 3,
+            Self::U32(_) => 4,
+            Self::U64(_) => 5,
+            Self::U128(_) => 6,
+            Self::Usize(_) => 7,
+            Self::I8(_) => 8,
+            Self::I16(_) => 9,
+            Self::I32(_) => 10,
+            Self::I64(_) => 11,
+            Self::I128(_) => 12,
+            Self::Isize(_) => 13,
+            Self::F64(_) => 14,
+            Self::String(_) => 15,
+            Self::Color(_) => 16,
+            Self::Email(_) => 17,
+            Self::PhoneNumber(_) => 18,
+        }
+    }
+
+    /// Returns the numeric representation of the value, if it is a number
+    fn as_number(&self) -> Option<Number> {
+        match self {
+            Self::U8(n) => Some(Number::Unsigned(u128::from(*n))),
+            Self::U16(n) => Some(Number::Unsigned(u128::from(*n))),
+            Self::U32(n) => Some(Number::Unsigned(u128::from(*n))),
+            Self::U64(n) => Some(Number::Unsigned(u128::from(*n))),
+            Self::U128(n) => Some(Number::Unsigned(*n)),
+            Self::Usize(n) => Some(Number::Unsigned(*n as u128)),
+            Self::I8(n) => Some(Number::Signed(i128::from(*n))),
+            Self::I16(n) => Some(Number::Signed(i128::from(*n))),
+            Self::I32(n) => Some(Number::Signed(i128::from(*n))),
+            Self::I64(n) => Some(Number::Signed(i128::from(*n))),
+            Self::I128(n) => Some(Number::Signed(*n)),
+            Self::Isize(n) => Some(Number::Signed(*n as i128)),
+            Self::F64(n) => Some(Number::Float(*n)),
+            _ => None,
+        }
+    }
+}
+
 impl StateValue {
     /// Returns whether `StateValue` is `StateValue::None`
     pub fn is_none(&self) -> bool {
@@ -233,3 +376,222 @@ impl StateValue {
         }
     }
 }
+
+/// Error returned when converting a [`State`] or a [`StateValue`] into a type which doesn't match the held value
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("could not convert {found} into `{expected}`")]
+pub struct StateConversionError {
+    /// Name of the requested type
+    pub expected: &'static str,
+    /// Debug representation of the value which couldn't be converted
+    pub found: String,
+}
+
+impl StateConversionError {
+    fn new(expected: &'static str, found: &impl fmt::Debug) -> Self {
+        Self {
+            expected,
+            found: format!("{found:?}"),
+        }
+    }
+}
+
+/// Implement `TryFrom<StateValue>` and `TryFrom<State>` (for `State::One`) for the types held by `StateValue`
+macro_rules! impl_try_from_state {
+    ($($variant:ident => $ty:ty),* $(,)?) => {
+        $(
+            impl TryFrom<StateValue> for $ty {
+                type Error = StateConversionError;
+
+                fn try_from(value: StateValue) -> Result<Self, Self::Error> {
+                    match value {
+                        StateValue::$variant(val) => Ok(val),
+                        value => Err(StateConversionError::new(stringify!($ty), &value)),
+                    }
+                }
+            }
+
+            impl TryFrom<State> for $ty {
+                type Error = StateConversionError;
+
+                fn try_from(state: State) -> Result<Self, Self::Error> {
+                    match state {
+                        State::One(value) => value.try_into(),
+                        state => Err(StateConversionError::new(stringify!($ty), &state)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_state! {
+    Bool => bool,
+    U8 => u8,
+    U16 => u16,
+    U32 => u32,
+    U64 => u64,
+    U128 => u128,
+    Usize => usize,
+    I8 => i8,
+    I16 => i16,
+    I32 => i32,
+    I64 => i64,
+    I128 => i128,
+    Isize => isize,
+    F64 => f64,
+    String => String,
+    Color => Color,
+    Email => Email,
+    PhoneNumber => PhoneNumber,
+}
+
+impl TryFrom<State> for Vec<StateValue> {
+    type Error = StateConversionError;
+
+    fn try_from(state: State) -> Result<Self, Self::Error> {
+        match state {
+            State::Vec(values) => Ok(values),
+            state => Err(StateConversionError::new("Vec<StateValue>", &state)),
+        }
+    }
+}
+
+impl TryFrom<State> for HashMap<String, StateValue> {
+    type Error = StateConversionError;
+
+    fn try_from(state: State) -> Result<Self, Self::Error> {
+        match state {
+            State::Map(values) => Ok(values),
+            state => Err(StateConversionError::new(
+                "HashMap<String, StateValue>",
+                &state,
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn should_sort_state_values() {
+        let mut values = vec![
+            StateValue::String(String::from("omar")),
+            StateValue::U8(3),
+            StateValue::None,
+            StateValue::I32(-2),
+            StateValue::F64(2.5),
+            StateValue::Bool(true),
+            StateValue::String(String::from("christian")),
+            StateValue::Usize(10),
+            StateValue::Bool(false),
+            StateValue::U128(u128::MAX),
+        ];
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(
+            values,
+            vec![
+                StateValue::None,
+                StateValue::Bool(false),
+                StateValue::Bool(true),
+                StateValue::I32(-2),
+                StateValue::F64(2.5),
+                StateValue::U8(3),
+                StateValue::Usize(10),
+                StateValue::U128(u128::MAX),
+                StateValue::String(String::from("christian")),
+                StateValue::String(String::from("omar")),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_compare_state_values() {
+        // Same value, different type
+        assert!(StateValue::U8(1) < StateValue::U16(1));
+        assert!(StateValue::I64(-1) < StateValue::U8(0));
+        assert!(StateValue::I128(i128::MIN) < StateValue::U128(0));
+        assert_eq!(
+            StateValue::F64(1.0).partial_cmp(&StateValue::F64(1.0)),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(
+            StateValue::F64(f64::NAN).partial_cmp(&StateValue::F64(1.0)),
+            None
+        );
+        assert_eq!(
+            StateValue::Color(Color::Red).partial_cmp(&StateValue::Color(Color::Red)),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(
+            StateValue::Color(Color::Red).partial_cmp(&StateValue::Color(Color::Blue)),
+            None
+        );
+        assert!(StateValue::String(String::from("zzz")) < StateValue::Color(Color::Red));
+        assert!(
+            StateValue::Email(Email::new("christian", "gmail.com"))
+                < StateValue::Email(Email::new("omar", "gmail.com"))
+        );
+    }
+
+    #[test]
+    fn should_compare_states() {
+        assert!(State::None < State::One(StateValue::None));
+        assert!(State::One(StateValue::U8(1)) < State::One(StateValue::U8(2)));
+        assert!(
+            State::Vec(vec![StateValue::U8(1), StateValue::U8(2)])
+                < State::Vec(vec![StateValue::U8(1), StateValue::U8(3)])
+        );
+        assert!(
+            State::Tup2((StateValue::Bool(true), StateValue::U8(1)))
+                > State::Tup2((StateValue::Bool(false), StateValue::U8(2)))
+        );
+        assert_eq!(
+            State::Map(HashMap::new()).partial_cmp(&State::Map(HashMap::new())),
+            Some(Ordering::Equal)
+        );
+        assert!(State::One(StateValue::U8(255)) < State::Vec(vec![]));
+    }
+
+    #[test]
+    fn should_convert_states() {
+        let value: usize = State::One(StateValue::Usize(4)).try_into().unwrap();
+        assert_eq!(value, 4);
+        let value: String = State::One(StateValue::String(String::from("omar")))
+            .try_into()
+            .unwrap();
+        assert_eq!(value, "omar");
+        let value: bool = StateValue::Bool(true).try_into().unwrap();
+        assert!(value);
+        let values: Vec<StateValue> = State::Vec(vec![StateValue::U8(1), StateValue::U8(2)])
+            .try_into()
+            .unwrap();
+        assert_eq!(values, vec![StateValue::U8(1), StateValue::U8(2)]);
+        let map: HashMap<String, StateValue> =
+            State::Map(HashMap::from([(String::from("a"), StateValue::None)]))
+                .try_into()
+                .unwrap();
+        assert_eq!(map.get("a"), Some(&StateValue::None));
+    }
+
+    #[test]
+    fn should_not_convert_mismatching_states() {
+        let err = usize::try_from(State::One(StateValue::U8(4))).unwrap_err();
+        assert_eq!(err.expected, "usize");
+        assert_eq!(err.to_string(), "could not convert U8(4) into `usize`");
+        assert!(String::try_from(State::None).is_err());
+        assert!(bool::try_from(State::Tup2((StateValue::Bool(true), StateValue::None))).is_err());
+        assert_eq!(
+            Vec::<StateValue>::try_from(State::One(StateValue::None))
+                .unwrap_err()
+                .to_string(),
+            "could not convert One(None) into `Vec<StateValue>`"
+        );
+        assert!(HashMap::<String, StateValue>::try_from(State::Vec(vec![])).is_err());
+    }
+}
@@ -134,6 +134,10 @@ where
     WindowResize,
     /// The event will be forwarded on a tick
     Tick,
+    /// The event will be forwarded when the terminal window gains focus
+    FocusGained,
+    /// The event will be forwarded when the terminal window loses focus
+    FocusLost,
     /// Event will be forwarded on this specific user event.
     /// The way user event is matched, depends on its [`PartialEq`] implementation
     User(UserEvent),
@@ -155,6 +159,8 @@ where
     /// - [`EventClause::Mouse`]: everything must match, column and row need to be within range
     /// - [`EventClause::WindowResize`]: matches only event type, not sizes
     /// - [`EventClause::Tick`]: matches tick event
+    /// - [`EventClause::FocusGained`]: matches focus gained event
+    /// - [`EventClause::FocusLost`]: matches focus lost event
     /// - [`EventClause::User`]: depends on UserEvent [`PartialEq`]
     /// - [`EventClause::Discriminant`]: matches only event type, not values
     fn forward(&self, ev: &Event<UserEvent>) -> bool {
@@ -164,6 +170,8 @@ where
             EventClause::Mouse(m) => ev.as_mouse().is_some_and(|ev| m.is_in_range(*ev)),
             EventClause::WindowResize => ev.as_window_resize(),
             EventClause::Tick => ev.as_tick(),
+            EventClause::FocusGained => ev.as_focus_gained(),
+            EventClause::FocusLost => ev.as_focus_lost(),
             EventClause::User(u) => Some(u) == ev.as_user(),
             EventClause::Discriminant(u) => {
                 Some(std::mem::discriminant(u)) == ev.as_user().map(|u| std::mem::discriminant(u))
@@ -772,6 +780,26 @@ mod test {
         );
     }
 
+    #[test]
+    fn event_clause_focus_should_forward() {
+        assert_eq!(
+            EventClause::<MockEvent>::FocusGained.forward(&Event::FocusGained),
+            true
+        );
+        assert_eq!(
+            EventClause::<MockEvent>::FocusGained.forward(&Event::FocusLost),
+            false
+        );
+        assert_eq!(
+            EventClause::<MockEvent>::FocusLost.forward(&Event::FocusLost),
+            true
+        );
+        assert_eq!(
+            EventClause::<MockEvent>::FocusLost.forward(&Event::Tick),
+            false
+        );
+    }
+
     #[test]
     fn event_clause_user_should_forward() {
         assert_eq!(
@@ -9,7 +9,9 @@ use std::hash::Hash;
 use ratatui::Frame;
 use thiserror::Error;
 
-use crate::ratatui::layout::Rect;
+use crate::event::{KeyEvent, MouseEvent, MouseEventKind};
+use crate::ratatui::layout::{Position, Rect};
+use crate::ratatui::widgets::Clear;
 use crate::{AttrValue, Attribute, Component, Event, Injector, State};
 
 /// A boxed component. Shorthand for View components map
@@ -30,6 +32,16 @@ pub enum ViewError {
     NoComponentToBlur,
 }
 
+/// Describes a change of focus in the view.
+/// Focus changes are only produced when the focused component actually changes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FocusChange<ComponentId> {
+    /// The component has lost focus
+    Blur(ComponentId),
+    /// The component has gained focus
+    Focus(ComponentId),
+}
+
 /// View is the wrapper and manager for all the components.
 /// A View is a container for all the components in a certain layout.
 /// Each View can have only one focused component at the time. At least one component must be always focused
@@ -41,12 +53,30 @@ where
 {
     /// Components Mounted onto View
     components: HashMap<ComponentId, WrappedComponent<Msg, UserEvent>>,
+    /// Ids of the mounted components, in mounting order
+    mount_order: Vec<ComponentId>,
     /// Current active component
     focus: Option<ComponentId>,
     /// Focus stack; used to determine which component should hold focus in case the current element is blurred
     focus_stack: Vec<ComponentId>,
     /// Property injectors
     injectors: Vec<Box<dyn Injector<ComponentId>>>,
+    /// If true, focus changes are recorded into `focus_changes`. (Default: False)
+    track_focus_changes: bool,
+    /// Focus changes not taken yet
+    focus_changes: Vec<FocusChange<ComponentId>>,
+    /// Areas where components have been rendered the last time, in rendering order
+    areas: Vec<RenderedArea<ComponentId>>,
+}
+
+/// Area where a component has been rendered
+struct RenderedArea<ComponentId> {
+    id: ComponentId,
+    area: Rect,
+    /// Count of the frame the component has been rendered in (see [`Frame::count`])
+    frame: usize,
+    /// Whether the component has been rendered over the rest of the UI (see [`View::view_over`])
+    over: bool,
 }
 
 impl<ComponentId, Msg, UserEvent> Default for View<ComponentId, Msg, UserEvent>
@@ -58,9 +88,13 @@ where
     fn default() -> Self {
         Self {
             components: HashMap::new(),
+            mount_order: Vec::new(),
             focus: None,
             focus_stack: Vec::new(),
             injectors: Vec::new(),
+            track_focus_changes: false,
+            focus_changes: Vec::new(),
+            areas: Vec::new(),
         }
     }
 }
@@ -83,8 +117,11 @@ where
         } else {
             // Insert
             self.components.insert(id.clone(), component);
+            self.mount_order.push(id.clone());
             // Inject properties
-            self.inject(id)
+            self.inject(id)?;
+            self.on_mount(id);
+            Ok(())
         }
     }
 
@@ -98,35 +135,52 @@ where
         }
         // Remove component from stack
         self.pop_from_stack(id);
+        self.areas.retain(|x| &x.id != id);
+        self.mount_order.retain(|x| x != id);
         // Umount
-        self.components.remove(id);
+        if let Some(mut component) = self.components.remove(id) {
+            component.on_umount();
+        }
         Ok(())
     }
 
-    /// Remount component. This method WON'T change the focus stack
+    /// Remount component. This method WON'T change the focus stack.
+    /// The previous component, if any, is umounted (see [`Component::on_umount`]) and returned
     pub fn remount(
         &mut self,
         id: &ComponentId,
         component: WrappedComponent<Msg, UserEvent>,
-    ) -> ViewResult<()> {
-        // Umount, but keep focus
+    ) -> ViewResult<Option<WrappedComponent<Msg, UserEvent>>> {
+        // Replace, but keep focus
         let had_focus = self.has_focus(id);
-        if self.mounted(id) {
-            self.components.remove(id);
+        let mut previous = self.components.insert(id.clone(), component);
+        match previous.as_mut() {
+            Some(previous) => previous.on_umount(),
+            // Replaced components keep their position
+            None => self.mount_order.push(id.clone()),
         }
-        // remount
-        self.components.insert(id.clone(), component);
         // Inject properties
         self.inject(id)?;
+        self.on_mount(id);
         // give focus if needed
-        if had_focus { self.active(id) } else { Ok(()) }
+        if had_focus {
+            self.active(id)?;
+        }
+        Ok(previous)
     }
 
     /// Umount all components in the view and clear focus stack and state
     pub fn umount_all(&mut self) {
+        self.components
+            .values_mut()
+            .for_each(|component| component.on_umount());
         self.components.clear();
+        self.mount_order.clear();
         self.focus_stack.clear();
-        self.focus = None;
+        if let Some(id) = self.focus.take() {
+            self.push_focus_change(FocusChange::Blur(id));
+        }
+        self.areas.clear();
     }
 
     /// Returns whether component `id` is mounted
@@ -134,19 +188,113 @@ where
         self.components.contains_key(id)
     }
 
+    /// Returns whether `who` has focus.
+    /// Returns `false` if `who` is not mounted
+    pub fn has_focus(&self, who: &ComponentId) -> bool {
+        match self.focus.as_ref() {
+            None => false,
+            Some(id) => who == id,
+        }
+    }
+
     /// Returns current active element (if any)
     pub(crate) fn focus(&self) -> Option<&ComponentId> {
         self.focus.as_ref()
     }
 
-    /// Render component called `id`
+    /// Render component called `id`.
+    /// The area is remembered, in order to give focus to the component when it's clicked (see [`View::component_at`])
+    ///
+    /// The component is not rendered at all if hidden (`Attribute::Display` set to `AttrValue::Flag(false)`)
     pub fn view(&mut self, id: &ComponentId, f: &mut Frame, area: Rect) {
+        self.render(id, f, area, false);
+    }
+
+    /// Clear `area` and render component `id` on top of it.
+    /// This is meant to render popups over the rest of the UI; the area is not cleared if the component
+    /// is not mounted or hidden.
+    ///
+    /// While the popup is rendered, the components rendered before it in the same frame can't get focus by clicking
+    /// on them (see [`View::component_at`]), so the popup keeps focus when clicking outside of it.
+    pub fn view_over(&mut self, id: &ComponentId, f: &mut Frame, area: Rect) {
+        if self
+            .components
+            .get(id)
+            .is_some_and(|c| is_visible(c.as_ref()))
+        {
+            f.render_widget(Clear, area);
+            self.render(id, f, area, true);
+        }
+    }
+
+    /// Render component `id`, if visible, and remember its area.
+    /// `over` tells whether the component is rendered over the rest of the UI
+    fn render(&mut self, id: &ComponentId, f: &mut Frame, area: Rect, over: bool) {
         if let Some(c) = self.components.get_mut(id) {
-            c.view(f, area);
+            self.areas.retain(|x| &x.id != id);
+            if is_visible(c.as_ref()) {
+                c.view(f, area);
+                self.areas.push(RenderedArea {
+                    id: id.clone(),
+                    area,
+                    frame: f.count(),
+                    over,
+                });
+            }
+        }
+    }
+
+    /// Render all the provided components, each one in its area.
+    /// Components are rendered in the provided order, so in case of overlapping areas, the last one is drawn on top.
+    /// Ids of components which are not mounted are skipped.
+    pub fn view_all(&mut self, f: &mut Frame, components: &[(ComponentId, Rect)]) {
+        for (id, area) in components {
+            self.view(id, f, *area);
         }
     }
 
+    /// Returns the ids of the mounted components, in mounting order.
+    /// Remounted components keep their original position.
+    pub fn mounted_ids(&self) -> Vec<&ComponentId> {
+        self.mount_order.iter().collect()
+    }
+
+    /// Returns the ids of the mounted components which are not hidden
+    /// (`Attribute::Display` not set to `AttrValue::Flag(false)`), in mounting order.
+    pub fn visible_components(&self) -> Vec<&ComponentId> {
+        self.mount_order
+            .iter()
+            .filter(|id| {
+                self.components
+                    .get(*id)
+                    .is_some_and(|c| is_visible(c.as_ref()))
+            })
+            .collect()
+    }
+
+    /// Returns the component which has been rendered at the provided position in the last frame.
+    /// Components which have not been rendered in the last frame are not considered, even if still mounted.
+    /// If many components have been rendered there, the last rendered one is returned.
+    ///
+    /// If a popup has been rendered with [`View::view_over`], the components rendered before it are not considered.
+    pub fn component_at(&self, column: u16, row: u16) -> Option<&ComponentId> {
+        let last_frame = self.areas.iter().map(|x| x.frame).max()?;
+        let rendered: Vec<&RenderedArea<ComponentId>> = self
+            .areas
+            .iter()
+            .filter(|x| x.frame == last_frame)
+            .collect();
+        // Components below the last popup can't be reached
+        let top = rendered.iter().rposition(|x| x.over).unwrap_or(0);
+        rendered[top..]
+            .iter()
+            .rev()
+            .find(|x| x.area.contains(Position::new(column, row)))
+            .map(|x| &x.id)
+    }
+
     /// Forward `event` (call `on()`) on component `id` and return a `Msg` if any.
+    /// The event is not forwarded if the component can't handle it (see [`Component::can_handle`]).
     /// Returns error if the component doesn't exist
     pub(crate) fn forward(
         &mut self,
@@ -155,7 +303,8 @@ where
     ) -> ViewResult<Option<Msg>> {
         match self.components.get_mut(id) {
             None => Err(ViewError::ComponentNotFound),
-            Some(c) => Ok(c.on(event)),
+            Some(c) if c.can_handle(&event) => Ok(c.on(event)),
+            Some(_) => Ok(None),
         }
     }
 
@@ -180,6 +329,17 @@ where
         }
     }
 
+    /// Set all the provided attributes, in order.
+    /// Returns error if any of the components doesn't exist; in this case no attribute is set at all.
+    pub fn attr_all(&mut self, attrs: Vec<(ComponentId, Attribute, AttrValue)>) -> ViewResult<()> {
+        if attrs.iter().any(|(id, _, _)| !self.mounted(id)) {
+            return Err(ViewError::ComponentNotFound);
+        }
+        attrs
+            .into_iter()
+            .try_for_each(|(id, attr, value)| self.attr(&id, attr, value))
+    }
+
     /// Get state for component `id`.
     /// Returns `Err` if component doesn't exist
     pub fn state(&self, id: &ComponentId) -> ViewResult<State> {
@@ -189,6 +349,32 @@ where
             .ok_or(ViewError::ComponentNotFound)
     }
 
+    /// Get the states of all the provided components at once (e.g. to submit a form).
+    /// Components which don't exist are skipped.
+    pub fn states(&self, ids: &[ComponentId]) -> HashMap<ComponentId, State> {
+        ids.iter()
+            .filter_map(|id| self.components.get(id).map(|c| (id.clone(), c.state())))
+            .collect()
+    }
+
+    /// Get the name of component `id` (see [`Component::name`]).
+    /// Returns `Err` if component doesn't exist
+    pub fn component_name(&self, id: &ComponentId) -> ViewResult<&'static str> {
+        self.components
+            .get(id)
+            .map(|c| c.name())
+            .ok_or(ViewError::ComponentNotFound)
+    }
+
+    /// Get the key bindings declared by component `id` (see [`Component::key_bindings`]).
+    /// Returns `Err` if component doesn't exist
+    pub fn key_bindings(&self, id: &ComponentId) -> ViewResult<Vec<(KeyEvent, &'static str)>> {
+        self.components
+            .get(id)
+            .map(|c| c.key_bindings())
+            .ok_or(ViewError::ComponentNotFound)
+    }
+
     // -- shorthands
 
     /// Shorthand for `attr(id, Attribute::Focus(AttrValue::Flag(true)))`.
@@ -198,8 +384,15 @@ where
     ///
     /// > NOTE: users should always use this function to give focus to components.
     pub fn active(&mut self, id: &ComponentId) -> ViewResult<()> {
+        let previous = self.focus.clone();
         self.set_focus(id, true)?;
         self.change_focus(id);
+        if previous.as_ref() != Some(id) {
+            if let Some(previous) = previous {
+                self.push_focus_change(FocusChange::Blur(previous));
+            }
+            self.push_focus_change(FocusChange::Focus(id.clone()));
+        }
         Ok(())
     }
 
@@ -212,6 +405,7 @@ where
     pub fn blur(&mut self) -> ViewResult<()> {
         if let Some(id) = self.focus.take() {
             self.set_focus(&id, false)?;
+            self.push_focus_change(FocusChange::Blur(id));
             self.focus_to_last();
             Ok(())
         } else {
@@ -219,6 +413,37 @@ where
         }
     }
 
+    /// If `event` is a mouse button press, give focus to the component rendered where the mouse has been clicked.
+    /// Nothing happens if there's no component there.
+    pub(crate) fn focus_clicked(&mut self, event: &Event<UserEvent>) {
+        if let Event::Mouse(MouseEvent {
+            kind: MouseEventKind::Down(_),
+            column,
+            row,
+            ..
+        }) = event
+        {
+            if let Some(id) = self.component_at(*column, *row).cloned() {
+                let _ = self.active(&id);
+            }
+        }
+    }
+
+    /// Set whether focus changes should be recorded, to be taken with [`View::take_focus_changes`].
+    /// Disabling the tracking discards the changes not taken yet.
+    pub fn set_track_focus_changes(&mut self, enabled: bool) {
+        self.track_focus_changes = enabled;
+        if !enabled {
+            self.focus_changes.clear();
+        }
+    }
+
+    /// Take all the focus changes occurred since the last call.
+    /// Changes are recorded only if enabled with [`View::set_track_focus_changes`].
+    pub fn take_focus_changes(&mut self) -> Vec<FocusChange<ComponentId>> {
+        std::mem::take(&mut self.focus_changes)
+    }
+
     // -- injectors
 
     /// Add an injector to the view
@@ -228,6 +453,13 @@ where
 
     // -- private
 
+    /// Record a focus change, if tracking is enabled
+    fn push_focus_change(&mut self, change: FocusChange<ComponentId>) {
+        if self.track_focus_changes {
+            self.focus_changes.push(change);
+        }
+    }
+
     /// Push component `id` to focus stack
     /// In case it is already in the focus stack,
     /// it will be first removed from it.
@@ -241,20 +473,12 @@ where
         self.focus_stack.retain(|x| x != id);
     }
 
-    /// Returns whether `who` has focus
-    pub(crate) fn has_focus(&self, who: &ComponentId) -> bool {
-        match self.focus.as_ref() {
-            None => false,
-            Some(id) => who == id,
-        }
-    }
-
-    /// If focus is `Some`, move it to the top of the stack and set it to `None`.
+    /// If focus is `Some` and different from `new_focus`, move it to the top of the stack and set it to `None`.
     /// Then pop from stack `new_focus` and set it to current `focus`.
     ///
     /// > Panics if `new_focus` doesn't exist in components
     fn change_focus(&mut self, new_focus: &ComponentId) {
-        if let Some(focus) = self.focus.take() {
+        if let Some(focus) = self.focus.take().filter(|x| x != new_focus) {
             // Remove focus (can't return error)
             let _ = self.set_focus(&focus, false);
             // Push to stack
@@ -288,6 +512,13 @@ where
         }
     }
 
+    /// Call the mount hook for component `id`
+    fn on_mount(&mut self, id: &ComponentId) {
+        if let Some(c) = self.components.get_mut(id) {
+            c.on_mount();
+        }
+    }
+
     /// Inject properties for `id` using view injectors
     fn inject(&mut self, id: &ComponentId) -> ViewResult<()> {
         for (attr, value) in self.properties_to_inject(id) {
@@ -302,6 +533,19 @@ where
     }
 }
 
+/// Returns whether the component is visible, according to its `Attribute::Display`.
+/// Components are visible unless the attribute is explicitly set to `false`.
+fn is_visible<Msg, UserEvent>(component: &dyn Component<Msg, UserEvent>) -> bool
+where
+    Msg: PartialEq,
+    UserEvent: Eq + PartialEq + Clone,
+{
+    !matches!(
+        component.query(Attribute::Display),
+        Some(AttrValue::Flag(false))
+    )
+}
+
 #[cfg(test)]
 mod test {
 
@@ -309,10 +553,18 @@ mod test {
 
     use super::*;
     use crate::StateValue;
-    use crate::event::{Key, KeyEvent};
+    use crate::event::{Key, KeyModifiers, MouseButton};
     use crate::mock::{
-        MockBarInput, MockComponentId, MockEvent, MockFooInput, MockInjector, MockMsg,
+        MOCK_LABEL_MOUNTS, MOCK_LABEL_UMOUNTED, MockBarInput, MockComponentId, MockEvent,
+        MockFooInput, MockInjector, MockLabel, MockMsg,
     };
+    use crate::ratatui::Terminal;
+    use crate::ratatui::backend::TestBackend;
+    use crate::ratatui::buffer::Buffer;
+    use crate::ratatui::text::Line;
+    use crate::ratatui::widgets::Paragraph;
+    use crate::test_utils::buffer_to_lines;
+    use crate::utils::centered_rect;
 
     #[test]
     fn default_view_should_be_empty() {
@@ -401,6 +653,47 @@ mod test {
         assert!(view.blur().is_ok());
         // Foo MUST have focus now
         assert!(view.has_focus(&MockComponentId::InputFoo));
+        // Remounting a component which wasn't mounted returns nothing
+        assert!(
+            view.remount(
+                &MockComponentId::InputOmar,
+                Box::new(MockFooInput::default())
+            )
+            .unwrap()
+            .is_none()
+        );
+        assert!(view.has_focus(&MockComponentId::InputFoo));
+        // Remounting the active component keeps focus on it and returns the previous one
+        assert!(
+            view.attr(
+                &MockComponentId::InputFoo,
+                Attribute::Text,
+                AttrValue::String(String::from("old"))
+            )
+            .is_ok()
+        );
+        let previous = view
+            .remount(
+                &MockComponentId::InputFoo,
+                Box::new(MockFooInput::default()),
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            previous.query(Attribute::Text),
+            Some(AttrValue::String(String::from("old")))
+        );
+        assert_eq!(
+            view.query(&MockComponentId::InputFoo, Attribute::Text)
+                .unwrap(),
+            None
+        );
+        assert!(view.has_focus(&MockComponentId::InputFoo));
+        assert_eq!(
+            view.query(&MockComponentId::InputFoo, Attribute::Focus)
+                .unwrap(),
+            Some(AttrValue::Flag(true))
+        );
     }
 
     #[test]
@@ -444,6 +737,325 @@ mod test {
         assert!(view.components.is_empty());
         assert!(view.focus_stack.is_empty());
         assert!(view.focus.is_none());
+        assert!(view.areas.is_empty());
+        // Components are gone
+        assert!(
+            view.forward(
+                &MockComponentId::InputFoo,
+                Event::Keyboard(KeyEvent::from(Key::Enter))
+            )
+            .is_err()
+        );
+        assert!(view.state(&MockComponentId::InputBar).is_err());
+        assert!(view.blur().is_err());
+        // Ids can be mounted again
+        assert!(
+            view.mount(
+                &MockComponentId::InputFoo,
+                Box::new(MockFooInput::default())
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn view_should_render_over_cleared_area() {
+        let mut view: View<MockComponentId, MockMsg, MockEvent> = View::default();
+        assert!(
+            view.mount(
+                &MockComponentId::InputFoo,
+                Box::new(MockFooInput::default())
+            )
+            .is_ok()
+        );
+        let mut terminal = Terminal::new(TestBackend::new(10, 4)).unwrap();
+        let frame = terminal
+            .draw(|f| {
+                let area = f.area();
+                f.render_widget(Paragraph::new(vec![Line::from("#".repeat(10)); 4]), area);
+                // Not mounted
+                view.view_over(&MockComponentId::InputBar, f, Rect::new(0, 0, 10, 1));
+                view.view_over(&MockComponentId::InputFoo, f, centered_rect(50, 50, area));
+            })
+            .unwrap();
+        assert_eq!(
+            frame.buffer,
+            &Buffer::with_lines(["##########", "##     ###", "##     ###", "##########"])
+        );
+        assert_eq!(view.component_at(3, 1), Some(&MockComponentId::InputFoo));
+    }
+
+    #[test]
+    fn view_should_keep_focus_on_popup_when_clicking_outside() {
+        let mut view: View<MockComponentId, MockMsg, MockEvent> = View::default();
+        assert!(
+            view.mount(
+                &MockComponentId::InputFoo,
+                Box::new(MockFooInput::default())
+            )
+            .is_ok()
+        );
+        assert!(
+            view.mount(
+                &MockComponentId::InputBar,
+                Box::new(MockBarInput::default())
+            )
+            .is_ok()
+        );
+        assert!(view.active(&MockComponentId::InputBar).is_ok());
+        let mut terminal = Terminal::new(TestBackend::new(20, 10)).unwrap();
+        terminal
+            .draw(|f| {
+                let area = f.area();
+                view.view(&MockComponentId::InputFoo, f, area);
+                view.view_over(&MockComponentId::InputBar, f, centered_rect(50, 50, area));
+            })
+            .unwrap();
+        // Background component can't be clicked while the popup is shown
+        assert_eq!(view.component_at(0, 0), None);
+        assert_eq!(view.component_at(8, 4), Some(&MockComponentId::InputBar));
+        view.focus_clicked(&mouse_event(MouseEventKind::Down(MouseButton::Left), 0, 0));
+        assert!(view.has_focus(&MockComponentId::InputBar));
+        // Once the popup is not rendered anymore, the background can be clicked again
+        terminal
+            .draw(|f| view.view(&MockComponentId::InputFoo, f, f.area()))
+            .unwrap();
+        view.focus_clicked(&mouse_event(MouseEventKind::Down(MouseButton::Left), 0, 0));
+        assert!(view.has_focus(&MockComponentId::InputFoo));
+    }
+
+    #[test]
+    fn view_should_render_all_components() {
+        let mut view: View<MockComponentId, MockMsg, MockEvent> = View::default();
+        assert!(
+            view.mount(
+                &MockComponentId::InputFoo,
+                Box::new(MockFooInput::default())
+            )
+            .is_ok()
+        );
+        assert!(
+            view.mount(
+                &MockComponentId::InputBar,
+                Box::new(MockBarInput::default())
+            )
+            .is_ok()
+        );
+        let mut terminal = Terminal::new(TestBackend::new(20, 10)).unwrap();
+        terminal
+            .draw(|f| {
+                view.view_all(
+                    f,
+                    &[
+                        (MockComponentId::InputFoo, Rect::new(0, 0, 20, 10)),
+                        (MockComponentId::InputOmar, Rect::new(0, 0, 20, 10)),
+                        (MockComponentId::InputBar, Rect::new(10, 0, 10, 10)),
+                    ],
+                )
+            })
+            .unwrap();
+        assert_eq!(view.component_at(2, 2), Some(&MockComponentId::InputFoo));
+        // Bar is drawn on top of foo
+        assert_eq!(view.component_at(12, 2), Some(&MockComponentId::InputBar));
+        assert_eq!(view.areas.len(), 2);
+    }
+
+    #[test]
+    fn view_should_not_render_hidden_components() {
+        let mut view: View<MockComponentId, MockMsg, MockEvent> = View::default();
+        assert!(
+            view.mount(
+                &MockComponentId::InputFoo,
+                Box::new(MockFooInput::default())
+            )
+            .is_ok()
+        );
+        assert!(
+            view.mount(
+                &MockComponentId::InputBar,
+                Box::new(MockBarInput::default())
+            )
+            .is_ok()
+        );
+        assert!(
+            view.attr(
+                &MockComponentId::InputFoo,
+                Attribute::Display,
+                AttrValue::Flag(true)
+            )
+            .is_ok()
+        );
+        for (id, text) in [
+            (MockComponentId::InputFoo, "foo"),
+            (MockComponentId::InputBar, "bar"),
+        ] {
+            assert!(
+                view.attr(&id, Attribute::Text, AttrValue::String(text.to_string()))
+                    .is_ok()
+            );
+        }
+        let mut terminal = Terminal::new(TestBackend::new(20, 1)).unwrap();
+        let mut render = |view: &mut View<MockComponentId, MockMsg, MockEvent>| {
+            let frame = terminal
+                .draw(|f| {
+                    view.view(&MockComponentId::InputFoo, f, Rect::new(0, 0, 10, 1));
+                    view.view(&MockComponentId::InputBar, f, Rect::new(10, 0, 10, 1));
+                })
+                .unwrap();
+            buffer_to_lines(frame.buffer)
+        };
+        assert_eq!(render(&mut view), vec!["foo       bar       "]);
+        assert_eq!(
+            view.visible_components(),
+            vec![&MockComponentId::InputFoo, &MockComponentId::InputBar]
+        );
+        assert_eq!(view.component_at(1, 0), Some(&MockComponentId::InputFoo));
+        // Hide foo
+        assert!(
+            view.attr(
+                &MockComponentId::InputFoo,
+                Attribute::Display,
+                AttrValue::Flag(false)
+            )
+            .is_ok()
+        );
+        // Foo cells are blank
+        assert_eq!(render(&mut view), vec!["          bar       "]);
+        assert_eq!(view.visible_components(), vec![&MockComponentId::InputBar]);
+        assert_eq!(view.component_at(1, 0), None);
+        assert_eq!(view.component_at(11, 0), Some(&MockComponentId::InputBar));
+    }
+
+    #[test]
+    fn view_should_return_mounted_ids_in_mounting_order() {
+        let mut view: View<MockComponentId, MockMsg, MockEvent> = View::default();
+        assert!(view.mounted_ids().is_empty());
+        let ids: Vec<MockComponentId> = (0..5)
+            .map(|x| MockComponentId::Dyn(format!("INPUT_{x}")))
+            .collect();
+        for id in ids.iter() {
+            assert!(view.mount(id, Box::new(MockFooInput::default())).is_ok());
+        }
+        assert_eq!(view.mounted_ids(), ids.iter().collect::<Vec<_>>());
+        // Umount
+        assert!(view.umount(&ids[1]).is_ok());
+        assert!(view.umount(&ids[3]).is_ok());
+        assert_eq!(view.mounted_ids(), vec![&ids[0], &ids[2], &ids[4]]);
+        // Remount keeps position for mounted components and appends the new ones
+        assert!(
+            view.remount(&ids[0], Box::new(MockFooInput::default()))
+                .is_ok()
+        );
+        assert!(
+            view.remount(&ids[1], Box::new(MockFooInput::default()))
+                .is_ok()
+        );
+        assert_eq!(view.mounted_ids(), vec![&ids[0], &ids[2], &ids[4], &ids[1]]);
+        // Umount all
+        view.umount_all();
+        assert!(view.mounted_ids().is_empty());
+    }
+
+    #[test]
+    fn view_should_get_component_name() {
+        let mut view: View<MockComponentId, MockMsg, MockEvent> = View::default();
+        assert!(
+            view.mount(
+                &MockComponentId::InputFoo,
+                Box::new(MockFooInput::default())
+            )
+            .is_ok()
+        );
+        assert!(
+            view.mount(&MockComponentId::Label, Box::new(MockLabel::default()))
+                .is_ok()
+        );
+        assert!(
+            view.component_name(&MockComponentId::InputFoo)
+                .unwrap()
+                .ends_with("::MockFooInput")
+        );
+        assert!(
+            view.component_name(&MockComponentId::Label)
+                .unwrap()
+                .ends_with("::MockLabel")
+        );
+        assert!(view.component_name(&MockComponentId::InputBar).is_err());
+    }
+
+    #[test]
+    fn view_should_get_key_bindings() {
+        let mut view: View<MockComponentId, MockMsg, MockEvent> = View::default();
+        assert!(
+            view.mount(
+                &MockComponentId::InputFoo,
+                Box::new(MockFooInput::default())
+            )
+            .is_ok()
+        );
+        assert!(
+            view.mount(&MockComponentId::Label, Box::new(MockLabel::default()))
+                .is_ok()
+        );
+        assert_eq!(
+            view.key_bindings(&MockComponentId::InputFoo)
+                .unwrap()
+                .into_iter()
+                .map(|(key, _)| key)
+                .collect::<Vec<KeyEvent>>(),
+            vec![
+                KeyEvent::from(Key::Left),
+                KeyEvent::from(Key::Right),
+                KeyEvent::from(Key::Enter),
+            ]
+        );
+        assert!(
+            view.key_bindings(&MockComponentId::Label)
+                .unwrap()
+                .is_empty()
+        );
+        assert!(view.key_bindings(&MockComponentId::InputBar).is_err());
+    }
+
+    #[test]
+    fn view_should_call_lifecycle_hooks() {
+        let mut view: View<MockComponentId, MockMsg, MockEvent> = View::default();
+        let mounts = |view: &View<MockComponentId, MockMsg, MockEvent>| {
+            view.query(
+                &MockComponentId::Label,
+                Attribute::Custom(MOCK_LABEL_MOUNTS),
+            )
+            .unwrap()
+        };
+        assert!(
+            view.mount(&MockComponentId::Label, Box::new(MockLabel::default()))
+                .is_ok()
+        );
+        assert_eq!(mounts(&view), Some(AttrValue::Length(1)));
+        // Mounting twice fails and doesn't call the hook again
+        assert!(
+            view.mount(&MockComponentId::Label, Box::new(MockLabel::default()))
+                .is_err()
+        );
+        assert_eq!(mounts(&view), Some(AttrValue::Length(1)));
+        // Remount umounts the previous component and mounts the new one
+        let previous = view
+            .remount(&MockComponentId::Label, Box::new(MockLabel::default()))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            previous.query(Attribute::Custom(MOCK_LABEL_UMOUNTED)),
+            Some(AttrValue::Flag(true))
+        );
+        assert_eq!(mounts(&view), Some(AttrValue::Length(1)));
+        assert_eq!(
+            view.query(
+                &MockComponentId::Label,
+                Attribute::Custom(MOCK_LABEL_UMOUNTED)
+            )
+            .unwrap(),
+            None
+        );
     }
 
     #[test]
@@ -556,6 +1168,82 @@ mod test {
         assert!(view.blur().is_err());
     }
 
+    #[test]
+    fn view_should_report_focus_changes() {
+        let mut view: View<MockComponentId, MockMsg, MockEvent> = View::default();
+        assert!(
+            view.mount(
+                &MockComponentId::InputFoo,
+                Box::new(MockFooInput::default())
+            )
+            .is_ok()
+        );
+        assert!(
+            view.mount(
+                &MockComponentId::InputBar,
+                Box::new(MockBarInput::default())
+            )
+            .is_ok()
+        );
+        // Not recorded by default
+        assert!(view.active(&MockComponentId::InputBar).is_ok());
+        assert!(view.blur().is_ok());
+        assert!(view.take_focus_changes().is_empty());
+        view.set_track_focus_changes(true);
+        assert!(view.active(&MockComponentId::InputFoo).is_ok());
+        assert_eq!(
+            view.take_focus_changes(),
+            vec![FocusChange::Focus(MockComponentId::InputFoo)]
+        );
+        // Focus didn't change
+        assert!(view.active(&MockComponentId::InputFoo).is_ok());
+        assert!(view.take_focus_changes().is_empty());
+        // Give focus to bar
+        assert!(view.active(&MockComponentId::InputBar).is_ok());
+        assert_eq!(
+            view.take_focus_changes(),
+            vec![
+                FocusChange::Blur(MockComponentId::InputFoo),
+                FocusChange::Focus(MockComponentId::InputBar)
+            ]
+        );
+        // Blur; focus goes back to foo
+        assert!(view.blur().is_ok());
+        assert_eq!(
+            view.take_focus_changes(),
+            vec![
+                FocusChange::Blur(MockComponentId::InputBar),
+                FocusChange::Focus(MockComponentId::InputFoo)
+            ]
+        );
+        // Failed active doesn't produce changes
+        assert!(view.active(&MockComponentId::InputOmar).is_err());
+        assert!(view.take_focus_changes().is_empty());
+        // Umounting all components reports the focus loss and keeps the changes not taken yet
+        assert!(view.active(&MockComponentId::InputBar).is_ok());
+        view.umount_all();
+        assert_eq!(
+            view.take_focus_changes(),
+            vec![
+                FocusChange::Blur(MockComponentId::InputFoo),
+                FocusChange::Focus(MockComponentId::InputBar),
+                FocusChange::Blur(MockComponentId::InputBar)
+            ]
+        );
+        assert!(view.focus().is_none());
+        // Disabling the tracking discards pending changes
+        assert!(
+            view.mount(
+                &MockComponentId::InputFoo,
+                Box::new(MockFooInput::default())
+            )
+            .is_ok()
+        );
+        assert!(view.active(&MockComponentId::InputFoo).is_ok());
+        view.set_track_focus_changes(false);
+        assert!(view.take_focus_changes().is_empty());
+    }
+
     #[test]
     fn view_should_forward_events() {
         let mut view: View<MockComponentId, MockMsg, MockEvent> = View::default();
@@ -581,6 +1269,26 @@ mod test {
         );
     }
 
+    #[test]
+    fn view_should_not_forward_events_which_component_cannot_handle() {
+        let mut view: View<MockComponentId, MockMsg, MockEvent> = View::default();
+        assert!(
+            view.mount(&MockComponentId::Label, Box::new(MockLabel::default()))
+                .is_ok()
+        );
+        let ev: Event<MockEvent> = Event::Keyboard(KeyEvent::from(Key::Char('a')));
+        assert_eq!(
+            view.forward(&MockComponentId::Label, ev).ok().unwrap(),
+            None
+        );
+        assert_eq!(
+            view.forward(&MockComponentId::Label, Event::Tick)
+                .ok()
+                .unwrap(),
+            Some(MockMsg::LabelEvent)
+        );
+    }
+
     #[test]
     fn view_should_read_and_write_attributes() {
         let mut view: View<MockComponentId, MockMsg, MockEvent> = View::default();
@@ -617,6 +1325,77 @@ mod test {
         );
     }
 
+    #[test]
+    fn view_should_set_many_attributes() {
+        let mut view: View<MockComponentId, MockMsg, MockEvent> = View::default();
+        assert!(
+            view.mount(
+                &MockComponentId::InputFoo,
+                Box::new(MockFooInput::default())
+            )
+            .is_ok()
+        );
+        assert!(
+            view.mount(
+                &MockComponentId::InputBar,
+                Box::new(MockBarInput::default())
+            )
+            .is_ok()
+        );
+        assert!(
+            view.attr_all(vec![
+                (
+                    MockComponentId::InputFoo,
+                    Attribute::Text,
+                    AttrValue::String(String::from("first"))
+                ),
+                (
+                    MockComponentId::InputBar,
+                    Attribute::Display,
+                    AttrValue::Flag(false)
+                ),
+                (
+                    MockComponentId::InputFoo,
+                    Attribute::Text,
+                    AttrValue::String(String::from("second"))
+                ),
+            ])
+            .is_ok()
+        );
+        // Applied in order
+        assert_eq!(
+            view.query(&MockComponentId::InputFoo, Attribute::Text)
+                .unwrap(),
+            Some(AttrValue::String(String::from("second")))
+        );
+        assert_eq!(
+            view.query(&MockComponentId::InputBar, Attribute::Display)
+                .unwrap(),
+            Some(AttrValue::Flag(false))
+        );
+        // Unknown component; nothing is set
+        assert!(
+            view.attr_all(vec![
+                (
+                    MockComponentId::InputFoo,
+                    Attribute::Text,
+                    AttrValue::String(String::from("third"))
+                ),
+                (
+                    MockComponentId::InputOmar,
+                    Attribute::Text,
+                    AttrValue::String(String::from("third"))
+                ),
+            ])
+            .is_err()
+        );
+        assert_eq!(
+            view.query(&MockComponentId::InputFoo, Attribute::Text)
+                .unwrap(),
+            Some(AttrValue::String(String::from("second")))
+        );
+    }
+
     #[test]
     fn view_should_read_state() {
         let mut view: View<MockComponentId, MockMsg, MockEvent> = View::default();
@@ -632,6 +1411,70 @@ mod test {
             State::One(StateValue::String(String::new()))
         );
         assert!(view.state(&MockComponentId::InputBar).is_err());
+        // State is read without giving focus to the component
+        for ch in ['h', 'i'] {
+            assert!(
+                view.forward(
+                    &MockComponentId::InputFoo,
+                    Event::Keyboard(KeyEvent::from(Key::Char(ch)))
+                )
+                .is_ok()
+            );
+        }
+        assert_eq!(
+            view.state(&MockComponentId::InputFoo).unwrap(),
+            State::One(StateValue::String(String::from("hi")))
+        );
+        assert!(view.focus().is_none());
+    }
+
+    #[test]
+    fn view_should_read_many_states() {
+        let mut view: View<MockComponentId, MockMsg, MockEvent> = View::default();
+        assert!(
+            view.mount(
+                &MockComponentId::InputFoo,
+                Box::new(MockFooInput::default())
+            )
+            .is_ok()
+        );
+        assert!(
+            view.mount(
+                &MockComponentId::InputBar,
+                Box::new(MockBarInput::default())
+            )
+            .is_ok()
+        );
+        assert!(
+            view.forward(
+                &MockComponentId::InputFoo,
+                Event::Keyboard(KeyEvent::from(Key::Char('a')))
+            )
+            .is_ok()
+        );
+        assert!(
+            view.forward(
+                &MockComponentId::InputBar,
+                Event::Keyboard(KeyEvent::from(Key::Char('b')))
+            )
+            .is_ok()
+        );
+        let states = view.states(&[
+            MockComponentId::InputFoo,
+            MockComponentId::InputBar,
+            MockComponentId::InputOmar,
+        ]);
+        assert_eq!(states.len(), 2);
+        assert_eq!(
+            states.get(&MockComponentId::InputFoo),
+            Some(&State::One(StateValue::String(String::from("a"))))
+        );
+        assert_eq!(
+            states.get(&MockComponentId::InputBar),
+            Some(&State::One(StateValue::String(String::from("b"))))
+        );
+        // Reading states doesn't change focus
+        assert!(view.focus().is_none());
     }
 
     #[test]
@@ -654,4 +1497,99 @@ mod test {
             AttrValue::String(String::from("hello, world!"))
         );
     }
+
+    #[test]
+    fn view_should_give_focus_to_clicked_component() {
+        let mut view: View<MockComponentId, MockMsg, MockEvent> = View::default();
+        assert!(
+            view.mount(
+                &MockComponentId::InputFoo,
+                Box::new(MockFooInput::default())
+            )
+            .is_ok()
+        );
+        assert!(
+            view.mount(
+                &MockComponentId::InputBar,
+                Box::new(MockBarInput::default())
+            )
+            .is_ok()
+        );
+        assert!(view.active(&MockComponentId::InputFoo).is_ok());
+        // Render components side by side
+        let mut terminal = Terminal::new(TestBackend::new(20, 10)).unwrap();
+        terminal
+            .draw(|f| {
+                view.view(&MockComponentId::InputFoo, f, Rect::new(0, 0, 10, 10));
+                view.view(&MockComponentId::InputBar, f, Rect::new(10, 0, 10, 10));
+            })
+            .unwrap();
+        assert_eq!(view.component_at(3, 4), Some(&MockComponentId::InputFoo));
+        assert_eq!(view.component_at(12, 4), Some(&MockComponentId::InputBar));
+        assert_eq!(view.component_at(30, 4), None);
+        // Click on bar
+        view.focus_clicked(&mouse_event(MouseEventKind::Down(MouseButton::Left), 12, 4));
+        assert!(view.has_focus(&MockComponentId::InputBar));
+        // Moving or clicking outside of components doesn't change focus
+        view.focus_clicked(&mouse_event(MouseEventKind::Moved, 3, 4));
+        assert!(view.has_focus(&MockComponentId::InputBar));
+        view.focus_clicked(&mouse_event(MouseEventKind::Down(MouseButton::Left), 30, 4));
+        assert!(view.has_focus(&MockComponentId::InputBar));
+        // Click on foo
+        view.focus_clicked(&mouse_event(MouseEventKind::Down(MouseButton::Left), 3, 4));
+        assert!(view.has_focus(&MockComponentId::InputFoo));
+        // Umounted components can't be clicked
+        assert!(view.umount(&MockComponentId::InputBar).is_ok());
+        assert_eq!(view.component_at(12, 4), None);
+    }
+
+    #[test]
+    fn view_should_forget_components_not_rendered_in_last_frame() {
+        let mut view: View<MockComponentId, MockMsg, MockEvent> = View::default();
+        assert!(
+            view.mount(
+                &MockComponentId::InputFoo,
+                Box::new(MockFooInput::default())
+            )
+            .is_ok()
+        );
+        assert!(
+            view.mount(
+                &MockComponentId::InputBar,
+                Box::new(MockBarInput::default())
+            )
+            .is_ok()
+        );
+        let mut terminal = Terminal::new(TestBackend::new(20, 10)).unwrap();
+        // Frame N: both components are rendered
+        terminal
+            .draw(|f| {
+                view.view(&MockComponentId::InputFoo, f, Rect::new(0, 0, 10, 10));
+                view.view(&MockComponentId::InputBar, f, Rect::new(10, 0, 10, 10));
+            })
+            .unwrap();
+        assert_eq!(view.component_at(3, 4), Some(&MockComponentId::InputFoo));
+        assert_eq!(view.component_at(12, 4), Some(&MockComponentId::InputBar));
+        // Frame N+1: foo is still mounted, but not rendered anymore
+        terminal
+            .draw(|f| {
+                view.view(&MockComponentId::InputBar, f, Rect::new(10, 0, 10, 10));
+            })
+            .unwrap();
+        assert!(view.mounted(&MockComponentId::InputFoo));
+        assert_eq!(view.component_at(3, 4), None);
+        assert_eq!(view.component_at(12, 4), Some(&MockComponentId::InputBar));
+        // Clicking where foo was doesn't give focus to it
+        view.focus_clicked(&mouse_event(MouseEventKind::Down(MouseButton::Left), 3, 4));
+        assert!(view.focus().is_none());
+    }
+
+    fn mouse_event(kind: MouseEventKind, column: u16, row: u16) -> Event<MockEvent> {
+        Event::Mouse(MouseEvent {
+            kind,
+            modifiers: KeyModifiers::NONE,
+            column,
+            row,
+        })
+    }
 }
@@ -39,6 +39,7 @@
 //! - `serialize`: add the serialize/deserialize trait implementation for `KeyEvent` and `Key`.
 //! - `crossterm`: use the [crossterm](https://github.com/crossterm-rs/crossterm) terminal backend
 //! - `termion`: use the [termion](https://github.com/redox-os/termion) terminal backend
+//! - `test-helpers`: add the `test_utils` module, to render components into a buffer in tests
 //!
 //! ### Create a tui-realm application 🪂
 //!
@@ -78,6 +79,9 @@ pub mod macros;
 pub mod mock;
 pub mod ratatui;
 pub mod terminal;
+#[cfg(any(test, feature = "test-helpers"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-helpers")))]
+pub mod test_utils;
 pub mod utils;
 // export async trait for async-ports
 #[cfg(feature = "async-ports")]
@@ -94,5 +98,8 @@ pub use self::core::event::{self, Event, NoUserEvent};
 pub use self::core::injector::Injector;
 pub use self::core::props::{self, AttrValue, Attribute, Props};
 pub use self::core::subscription::{EventClause as SubEventClause, Sub, SubClause};
-pub use self::core::{Component, MockComponent, State, StateValue, Update, ViewError, command};
+pub use self::core::{
+    Component, FocusChange, MockComponent, State, StateConversionError, StateValue, Update,
+    ViewError, command,
+};
 pub use self::ratatui::Frame;
@@ -7,6 +7,7 @@ use ratatui::Frame;
 use super::{MockEvent, MockMsg};
 use crate::command::{Cmd, CmdResult, Direction};
 use crate::event::{Event, Key, KeyEvent, KeyModifiers};
+use crate::ratatui::widgets::Paragraph;
 use crate::{AttrValue, Attribute, Component, MockComponent, Props, State, StateValue};
 
 /// Mocked component implementing `MockComponent`
@@ -25,7 +26,14 @@ impl Default for MockInput {
 }
 
 impl MockComponent for MockInput {
-    fn view(&mut self, _: &mut Frame, _: crate::ratatui::layout::Rect) {}
+    fn view(&mut self, frame: &mut Frame, area: crate::ratatui::layout::Rect) {
+        if let Some(AttrValue::String(text)) = self.props.get_ref(Attribute::Text) {
+            frame.render_widget(
+                Paragraph::new(text.as_str()).style(self.props.style()),
+                area,
+            );
+        }
+    }
 
     fn query(&self, attr: Attribute) -> Option<AttrValue> {
         self.props.get(attr)
@@ -125,6 +133,14 @@ impl Component<MockMsg, MockEvent> for MockFooInput {
             _ => None,
         }
     }
+
+    fn key_bindings(&self) -> Vec<(KeyEvent, &'static str)> {
+        vec![
+            (KeyEvent::from(Key::Left), "Move cursor left"),
+            (KeyEvent::from(Key::Right), "Move cursor right"),
+            (KeyEvent::from(Key::Enter), "Submit"),
+        ]
+    }
 }
 
 #[derive(MockComponent, Default)]
@@ -152,6 +168,8 @@ impl Component<MockMsg, MockEvent> for MockBarInput {
                 modifiers: KeyModifiers::NONE,
             }) => return Some(MockMsg::BarSubmit(self.component.states.text.clone())),
             Event::Tick => return Some(MockMsg::BarTick),
+            Event::FocusGained => return Some(MockMsg::BarTerminalFocus(true)),
+            Event::FocusLost => return Some(MockMsg::BarTerminalFocus(false)),
             _ => Cmd::None,
         };
         match self.component.perform(cmd) {
@@ -162,3 +180,43 @@ impl Component<MockMsg, MockEvent> for MockBarInput {
         }
     }
 }
+
+/// Custom attribute counting how many times the [`MockLabel`] has been mounted
+pub const MOCK_LABEL_MOUNTS: &str = "mock-label-mounts";
+/// Custom attribute set by [`MockLabel`] once umounted
+pub const MOCK_LABEL_UMOUNTED: &str = "mock-label-umounted";
+
+/// Mocked read-only component, which only handles ticks.
+/// It also tracks its lifecycle in the [`MOCK_LABEL_MOUNTS`] and [`MOCK_LABEL_UMOUNTED`] attributes
+#[derive(MockComponent, Default)]
+pub struct MockLabel {
+    component: MockInput,
+}
+
+impl Component<MockMsg, MockEvent> for MockLabel {
+    fn on(&mut self, _: Event<MockEvent>) -> Option<MockMsg> {
+        Some(MockMsg::LabelEvent)
+    }
+
+    fn can_handle(&self, ev: &Event<MockEvent>) -> bool {
+        matches!(ev, Event::Tick)
+    }
+
+    fn on_mount(&mut self) {
+        let mounts = self
+            .query(Attribute::Custom(MOCK_LABEL_MOUNTS))
+            .map(AttrValue::unwrap_length)
+            .unwrap_or_default();
+        self.attr(
+            Attribute::Custom(MOCK_LABEL_MOUNTS),
+            AttrValue::Length(mounts + 1),
+        );
+    }
+
+    fn on_umount(&mut self) {
+        self.attr(
+            Attribute::Custom(MOCK_LABEL_UMOUNTED),
+            AttrValue::Flag(true),
+        );
+    }
+}
@@ -10,7 +10,9 @@ use crate::{AttrValue, Attribute, Injector};
 
 // -- modules
 mod components;
-pub use components::{MockBarInput, MockFooInput, MockInput};
+pub use components::{
+    MOCK_LABEL_MOUNTS, MOCK_LABEL_UMOUNTED, MockBarInput, MockFooInput, MockInput, MockLabel,
+};
 
 // -- event
 
@@ -29,6 +31,7 @@ pub enum MockComponentId {
     InputBar,
     InputFoo,
     InputOmar,
+    Label,
     Dyn(String),
 }
 
@@ -88,6 +91,8 @@ pub enum MockMsg {
     BarInputChanged(String),
     BarSubmit(String),
     BarTick,
+    BarTerminalFocus(bool),
+    LabelEvent,
 }
 
 // -- injector
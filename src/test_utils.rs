@@ -0,0 +1,71 @@
+//! ## Test utils
+//!
+//! This module exposes utilities to test the rendering of components, without a real terminal.
+//! It requires the `test-helpers` feature.
+
+use crate::MockComponent;
+use crate::ratatui::Terminal;
+use crate::ratatui::backend::TestBackend;
+use crate::ratatui::buffer::Buffer;
+use crate::ratatui::layout::Rect;
+
+/// Render `component` in `area` and return the rendered buffer.
+///
+/// The buffer starts at the terminal origin and it's large enough to contain `area`,
+/// so cells can be checked at the same coordinates used to render the component.
+pub fn render_to_buffer<C>(component: &mut C, area: Rect) -> Buffer
+where
+    C: MockComponent + ?Sized,
+{
+    let mut terminal = Terminal::new(TestBackend::new(area.right(), area.bottom()))
+        .expect("test backend never fails");
+    terminal
+        .draw(|f| component.view(f, area))
+        .expect("test backend never fails")
+        .buffer
+        .clone()
+}
+
+/// Returns the content of each row of `buffer`.
+///
+/// > NOTE: wide characters (e.g. emojis) are followed by the cells they cover
+pub fn buffer_to_lines(buffer: &Buffer) -> Vec<String> {
+    let width = usize::from(buffer.area.width);
+    if width == 0 {
+        return Vec::new();
+    }
+    buffer
+        .content
+        .chunks(width)
+        .map(|row| row.iter().map(|cell| cell.symbol()).collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::mock::{MockEvent, MockLabel, MockMsg};
+    use crate::{AttrValue, Attribute, Component};
+
+    #[test]
+    fn should_render_component_to_buffer() {
+        let mut label = MockLabel::default();
+        label.attr(Attribute::Text, AttrValue::String(String::from("hello")));
+        let buffer = render_to_buffer(&mut label, Rect::new(2, 1, 6, 1));
+        assert_eq!(buffer.area, Rect::new(0, 0, 8, 2));
+        assert_eq!(buffer[(2, 1)].symbol(), "h");
+        assert_eq!(buffer_to_lines(&buffer), vec!["        ", "  hello "]);
+        // Trait objects can be rendered too
+        let component: &mut dyn Component<MockMsg, MockEvent> = &mut MockLabel::default();
+        let buffer = render_to_buffer(component, Rect::new(0, 0, 3, 1));
+        assert_eq!(buffer_to_lines(&buffer), vec!["   "]);
+    }
+
+    #[test]
+    fn should_convert_empty_buffer_to_lines() {
+        assert!(buffer_to_lines(&Buffer::empty(Rect::default())).is_empty());
+    }
+}
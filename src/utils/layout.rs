@@ -0,0 +1,48 @@
+//! ## Layout
+//!
+//! This module exposes layout utilities
+
+use crate::ratatui::layout::Rect;
+
+/// Returns a rect centered in `area`, taking `percent_x` of its width and `percent_y` of its height.
+/// Percentages greater than 100 are treated as 100.
+///
+/// This is useful to compute the area of popups, to be rendered with [`crate::Application::view_over`].
+pub fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
+    let width = scale(area.width, percent_x);
+    let height = scale(area.height, percent_y);
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
+
+/// Returns `percent` of `value`
+fn scale(value: u16, percent: u16) -> u16 {
+    (u32::from(value) * u32::from(percent.min(100)) / 100) as u16
+}
+
+#[cfg(test)]
+mod test {
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn should_get_centered_rect() {
+        let area = Rect::new(0, 0, 100, 40);
+        assert_eq!(centered_rect(50, 50, area), Rect::new(25, 10, 50, 20));
+        assert_eq!(centered_rect(100, 100, area), area);
+        assert_eq!(centered_rect(200, 100, area), area);
+        assert_eq!(centered_rect(0, 0, area), Rect::new(50, 20, 0, 0));
+        assert_eq!(centered_rect(30, 25, area), Rect::new(35, 15, 30, 10));
+        // Area with offset
+        assert_eq!(
+            centered_rect(50, 50, Rect::new(10, 5, 20, 11)),
+            Rect::new(15, 8, 10, 5)
+        );
+    }
+}
@@ -2,8 +2,10 @@
 //!
 //! This module exposes utilities
 
+mod layout;
 pub mod parser;
 mod types;
 
+pub use layout::centered_rect;
 // export types
 pub use types::{Email, PhoneNumber};
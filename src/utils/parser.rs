@@ -15,7 +15,7 @@ use crate::ratatui::style::Color;
  * - group 3: Blue
  */
 static COLOR_HEX_REGEX: Lazy<Regex> =
-    lazy_regex!(r"#(:?[0-9a-fA-F]{2})(:?[0-9a-fA-F]{2})(:?[0-9a-fA-F]{2})");
+    lazy_regex!(r"^#([0-9a-fA-F]{2})([0-9a-fA-F]{2})([0-9a-fA-F]{2})$");
 /**
  * Regex matches:
  * - group 2: Red
@@ -23,7 +23,7 @@ static COLOR_HEX_REGEX: Lazy<Regex> =
  * - group 6: blue
  */
 static COLOR_RGB_REGEX: Lazy<Regex> = lazy_regex!(
-    r"^(rgb)?\(?([01]?\d\d?|2[0-4]\d|25[0-5])(\W+)([01]?\d\d?|2[0-4]\d|25[0-5])\W+(([01]?\d\d?|2[0-4]\d|25[0-5])\)?)"
+    r"^(rgb)?\(?([01]?[0-9][0-9]?|2[0-4][0-9]|25[0-5])(\W+)([01]?[0-9][0-9]?|2[0-4][0-9]|25[0-5])\W+(([01]?[0-9][0-9]?|2[0-4][0-9]|25[0-5])\)?)$"
 );
 
 /**
@@ -280,18 +280,12 @@ pub fn parse_color(color: &str) -> Option<Color> {
 /// - "#f0ab05"
 /// - "#AA33BC"
 fn parse_hex_color(color: &str) -> Option<Color> {
-    COLOR_HEX_REGEX.captures(color).map(|groups| {
-        Color::Rgb(
-            u8::from_str_radix(groups.get(1).unwrap().as_str(), 16)
-                .ok()
-                .unwrap(),
-            u8::from_str_radix(groups.get(2).unwrap().as_str(), 16)
-                .ok()
-                .unwrap(),
-            u8::from_str_radix(groups.get(3).unwrap().as_str(), 16)
-                .ok()
-                .unwrap(),
-        )
+    COLOR_HEX_REGEX.captures(color).and_then(|groups| {
+        Some(Color::Rgb(
+            u8::from_str_radix(groups.get(1)?.as_str(), 16).ok()?,
+            u8::from_str_radix(groups.get(2)?.as_str(), 16).ok()?,
+            u8::from_str_radix(groups.get(3)?.as_str(), 16).ok()?,
+        ))
     })
 }
 
@@ -301,12 +295,12 @@ fn parse_hex_color(color: &str) -> Option<Color> {
 /// - "rgb(255,64,32)"
 /// - "255, 64, 32"
 fn parse_rgb_color(color: &str) -> Option<Color> {
-    COLOR_RGB_REGEX.captures(color).map(|groups| {
-        Color::Rgb(
-            u8::from_str(groups.get(2).unwrap().as_str()).ok().unwrap(),
-            u8::from_str(groups.get(4).unwrap().as_str()).ok().unwrap(),
-            u8::from_str(groups.get(6).unwrap().as_str()).ok().unwrap(),
-        )
+    COLOR_RGB_REGEX.captures(color).and_then(|groups| {
+        Some(Color::Rgb(
+            u8::from_str(groups.get(2)?.as_str()).ok()?,
+            u8::from_str(groups.get(4)?.as_str()).ok()?,
+            u8::from_str(groups.get(6)?.as_str()).ok()?,
+        ))
     })
 }
 
@@ -549,4 +543,30 @@ mod test {
         );
         assert!(parse_color("redd").is_none());
     }
+
+    #[test]
+    fn utils_parse_hex_color() {
+        assert_eq!(parse_color("#f0ab05"), Some(Color::Rgb(240, 171, 5)));
+        assert_eq!(parse_color("#AA33BC"), Some(Color::Rgb(170, 51, 188)));
+        assert!(parse_color("f0ab05").is_none());
+        assert!(parse_color("#f0ab0").is_none());
+        assert!(parse_color("#f0ab05ff").is_none());
+        assert!(parse_color("#g0ab05").is_none());
+        assert!(parse_color("#:f:a:0").is_none());
+    }
+
+    #[test]
+    fn utils_parse_rgb_color() {
+        assert_eq!(parse_color("rgb(255,64,32)"), Some(Color::Rgb(255, 64, 32)));
+        assert_eq!(parse_color("RGB(0, 0, 0)"), Some(Color::Rgb(0, 0, 0)));
+        assert_eq!(parse_color("255, 64, 32"), Some(Color::Rgb(255, 64, 32)));
+        // Out of range components
+        assert!(parse_color("rgb(256, 64, 32)").is_none());
+        assert!(parse_color("rgb(255, 300, 32)").is_none());
+        assert!(parse_color("rgb(255, 64, 320)").is_none());
+        assert!(parse_color("rgb(255, 64)").is_none());
+        // Non-ASCII digits
+        assert!(parse_color("rgb(٣,0,0)").is_none());
+        assert!(parse_color("٣,0,0").is_none());
+    }
 }
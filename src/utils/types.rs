@@ -3,7 +3,7 @@
 //! This module exposes types used by utilities
 
 /// Represents a phone number
-#[derive(Eq, PartialEq, Debug, Clone)]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Debug, Clone)]
 pub struct PhoneNumber {
     /// Prefix number (without `00` or `+`)
     pub prefix: Option<String>,
@@ -30,7 +30,7 @@ impl PhoneNumber {
 }
 
 /// Represents an email address
-#[derive(Eq, PartialEq, Debug, Clone)]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Debug, Clone)]
 pub struct Email {
     /// Address name (e.g. `foo.bar@preema.it` => `foo.bar`)
     pub name: String,